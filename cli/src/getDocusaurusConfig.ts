@@ -120,6 +120,11 @@ export default function getDocusaurusConfig({
           apiCategories,
           binaryPath,
           autoSectionPath,
+          dialect: config.dialect,
+          compat: config.compat,
+          rojoProject: config.rojoProject,
+          include: config.include,
+          exclude: config.exclude,
         },
       ],
       "docusaurus-lunr-search",
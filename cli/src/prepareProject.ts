@@ -49,6 +49,11 @@ export type Config = Partial<{
   classOrder: ClassOrder
   apiCategories: string[]
   autoSectionPath?: string
+  dialect: string
+  compat: string[]
+  rojoProject: string
+  include: string[]
+  exclude: string[]
 
   // Docusaurus
   docusaurus: Partial<{
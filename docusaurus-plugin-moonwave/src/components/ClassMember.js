@@ -18,6 +18,7 @@ export default function ClassMember({
   private: isPrivate,
   yields,
   readonly: readOnly,
+  inherited_from: inheritedFrom,
   source,
   sourceUrl,
   luaClassName,
@@ -72,6 +73,11 @@ export default function ClassMember({
         {unreleased && !deprecated && (
           <span className={styles.releaseVersion}>unreleased</span>
         )}
+        {inheritedFrom && (
+          <span className={styles.releaseVersion}>
+            inherited from {inheritedFrom}
+          </span>
+        )}
 
         {sourceUrl && (
           <SourceLink to={`${sourceUrl}/${source.path}#L${source.line}`} />
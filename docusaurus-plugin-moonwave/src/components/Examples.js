@@ -0,0 +1,21 @@
+import CodeBlock from "@theme/CodeBlock"
+import Heading from "@theme/Heading"
+import React from "react"
+
+export default function Examples({ examples }) {
+  if (!examples || examples.length === 0) {
+    return null
+  }
+
+  return (
+    <>
+      <Heading as="h4">{examples.length > 1 ? "Examples" : "Example"}</Heading>
+
+      {examples.map(({ title, language, code }, i) => (
+        <CodeBlock key={i} language={language} title={title}>
+          {code}
+        </CodeBlock>
+      ))}
+    </>
+  )
+}
@@ -16,6 +16,8 @@ import {
 import Admonition from "./Admonition.js"
 import Badge from "./Badge.js"
 import ClassMember from "./ClassMember.js"
+import Examples from "./Examples.js"
+import LuaEvent from "./LuaEvent.js"
 import LuaFunction from "./LuaFunction.js"
 import LuaProp from "./LuaProp.js"
 import LuaTypeDef from "./LuaTypeDef.js"
@@ -36,6 +38,14 @@ const SECTIONS = [
     name: "functions",
     component: LuaFunction,
   },
+  {
+    name: "callbacks",
+    component: LuaFunction,
+  },
+  {
+    name: "events",
+    component: LuaEvent,
+  },
 ]
 
 const capitalize = (text) => text[0].toUpperCase() + text.substring(1)
@@ -180,7 +190,12 @@ export default function LuaClass({
       .filter((member) => !member.ignore)
       .filter((member) => !member.private || showPrivate)
       .sort((memberA, memberB) => {
-        if (!memberA.deprecated && memberB.deprecated) {
+        // Compared with `true`, since every object inherits a `constructor` property
+        if (memberA.constructor === true && memberB.constructor !== true) {
+          return -1
+        } else if (memberA.constructor !== true && memberB.constructor === true) {
+          return 1
+        } else if (!memberA.deprecated && memberB.deprecated) {
           return -1
         } else if (memberA.deprecated && !memberB.deprecated) {
           return 1
@@ -351,7 +366,16 @@ export default function LuaClass({
                             </Admonition>
                           )}
 
+                          {luaClass.data_model_path && (
+                            <Markdown
+                              content={`\`\`\`lua\nlocal ${
+                                luaClass.name.split(".").pop()
+                              } = require(game.${luaClass.data_model_path})\n\`\`\``}
+                            />
+                          )}
+
                           <Markdown content={luaClass.desc} />
+                          <Examples examples={luaClass.examples} />
                         </header>
 
                         {SECTIONS.map((section) => (
@@ -0,0 +1,41 @@
+import React from "react"
+import Examples from "./Examples.js"
+import InlineDescription from "./InlineDescription.js"
+import LuaType from "./LuaType.js"
+import Markdown from "./Markdown.js"
+import styles from "./styles.module.css"
+import { PrOp } from "./Syntax.js"
+
+export default function LuaEvent({
+  luaClassName,
+  name,
+  params,
+  desc,
+  examples,
+}) {
+  return (
+    <>
+      <div className={styles.memberString}>
+        <code>
+          {luaClassName}.{name}
+        </code>
+        <PrOp>(</PrOp>
+        {params.length > 0 && (
+          <div className={styles.inset}>
+            {params.map((param, index) => (
+              <div key={index}>
+                <code>{param.name}:&nbsp;</code>
+                <LuaType code={param.lua_type} />
+                {index !== params.length - 1 && <code>,</code>}
+                {param.desc && <InlineDescription content={param.desc} />}
+              </div>
+            ))}
+          </div>
+        )}
+        <PrOp>)</PrOp>
+      </div>
+      <Markdown content={desc} />
+      <Examples examples={examples} />
+    </>
+  )
+}
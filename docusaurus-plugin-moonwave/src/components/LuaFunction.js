@@ -1,6 +1,7 @@
 import Heading from "@theme/Heading"
 import clsx from "clsx"
 import React from "react"
+import Examples from "./Examples.js"
 import InlineDescription from "./InlineDescription.js"
 import LuaType from "./LuaType.js"
 import LuaTypeDef from "./LuaTypeDef.js"
@@ -23,6 +24,7 @@ export default function LuaFunction({
   desc,
   function_type: functionType,
   errors,
+  examples,
   extraTypes,
 }) {
   return (
@@ -112,6 +114,8 @@ export default function LuaFunction({
 
       <Markdown content={desc} />
 
+      <Examples examples={examples} />
+
       {errors && (
         <>
           <Heading as="h3">Errors</Heading>
@@ -1,4 +1,5 @@
 import React from "react"
+import Examples from "./Examples.js"
 import LuaType from "./LuaType.js"
 import Markdown from "./Markdown.js"
 import styles from "./styles.module.css"
@@ -7,6 +8,7 @@ export default function LuaProp({
   luaClassName,
   name,
   desc,
+  examples,
   lua_type: luaType,
 }) {
   return (
@@ -18,6 +20,7 @@ export default function LuaProp({
         <LuaType code={luaType} />
       </div>
       <Markdown content={desc} />
+      <Examples examples={examples} />
     </>
   )
 }
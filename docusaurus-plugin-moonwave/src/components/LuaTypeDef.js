@@ -1,4 +1,5 @@
 import React from "react"
+import Examples from "./Examples.js"
 import InlineDescription from "./InlineDescription.js"
 import LuaType from "./LuaType.js"
 import Markdown from "./Markdown.js"
@@ -40,6 +41,7 @@ export default function LuaTypeDef({
   luaClassName,
   name,
   desc,
+  examples,
   lua_type: luaType,
   fields,
 }) {
@@ -53,6 +55,7 @@ export default function LuaTypeDef({
         )}
       </div>
       <Markdown content={desc} />
+      <Examples examples={examples} />
     </>
   )
 }
@@ -1,5 +1,6 @@
 import { resolve } from "path"
 import { existsSync } from "fs"
+import { mkdir, readFile } from "fs/promises"
 import { promisify } from "util"
 const exec = promisify(require("child_process").exec)
 import { generateRobloxTypes } from "./generateRobloxTypes.js"
@@ -219,11 +220,20 @@ function processNestedItems(items, nameSet, filteredContent, listedNames) {
   return result
 }
 
+// Callbacks are implemented by users rather than called, so they get their own section
+function separateCallbacks(luaClass) {
+  return {
+    ...luaClass,
+    functions: luaClass.functions.filter((fn) => !fn.callback),
+    callbacks: luaClass.functions.filter((fn) => fn.callback),
+  }
+}
+
 function parseApiCategories(luaClass, apiCategories) {
   const tocData = []
 
   // Loop through each member type of a LuaClass and check if it has any tagged children. If the tags match any tag provided by the user with the apiCategories config option, add it to it's own subheading in the table of contents
-  const SECTIONS = ["types", "properties", "functions"]
+  const SECTIONS = ["types", "properties", "functions", "callbacks", "events"]
   SECTIONS.forEach((section) => {
     const tagSet = new Set(
       luaClass[section]
@@ -319,8 +329,10 @@ async function generateTypeLinks(nameSet, luaClasses, baseUrl) {
     const entries = [
       luaClass,
       ...luaClass.functions,
+      ...luaClass.callbacks,
       ...luaClass.properties,
       ...luaClass.types,
+      ...luaClass.events,
     ]
 
     entries
@@ -409,30 +421,62 @@ export default (context, options) => ({
     const basePath = options.projectDir || resolve(process.cwd(), "..")
 
     const binaryPath = options.binaryPath ?? "moonwave-extractor"
+    const dialectArg = options.dialect ? ` --dialect "${options.dialect}"` : ""
+    const compatArgs = (options.compat ?? [])
+      .map((compat) => ` --compat "${compat}"`)
+      .join("")
+    const rojoProjectArg = options.rojoProject
+      ? ` --rojo-project "${resolve(basePath, options.rojoProject).replace(
+          /\\/g,
+          "/"
+        )}"`
+      : ""
+    const globArgs = [
+      ...(options.include ?? []).map((glob) => ` --include "${glob}"`),
+      ...(options.exclude ?? []).map((glob) => ` --exclude "${glob}"`),
+    ].join("")
+
+    if (options.code.length === 0) {
+      return []
+    }
 
-    const api = await Promise.all(
-      options.code.map((root) =>
-        exec(
-          `"${binaryPath}" extract "${root.replace(
-            /\\/g,
-            "/"
-          )}" --base "${basePath}"`,
-          {
-            maxBuffer: 10 * 1024 * 1024,
-          }
-        )
-          .then(({ stdout, stderr }) => {
-            if (stderr.length > 0) {
-              return Promise.reject(stderr)
-            }
+    // Every root is extracted by one process, so that entries in one root
+    // can be within classes in another
+    const roots = options.code
+      .map((root) => ` "${root.replace(/\\/g, "/")}"`)
+      .join("")
+
+    // The docs are written to a file rather than stdout, since they can be
+    // larger than the buffer that exec keeps
+    const outputPath = resolve(
+      context.generatedFilesDir,
+      "moonwave-api.json"
+    )
+    await mkdir(context.generatedFilesDir, { recursive: true })
+
+    const { stderr } = await exec(
+      `"${binaryPath}" extract${roots} --base "${basePath}"${dialectArg}${compatArgs}${rojoProjectArg}${globArgs} --output "${outputPath.replace(
+        /\\/g,
+        "/"
+      )}" --compact`,
+      {
+        // Warnings still go to stderr, and a large project can have many
+        maxBuffer: 10 * 1024 * 1024,
+      }
+    )
 
-            return stdout
-          })
-          .then((raw) => JSON.parse(raw))
-      )
+    // Errors make the extractor exit with a non-zero code, which rejects the
+    // promise, so anything left on stderr is a warning
+    if (stderr.length > 0) {
+      console.warn(stderr)
+    }
+
+    const { classes, modules = [] } = JSON.parse(
+      await readFile(outputPath, "utf8")
     )
 
-    return api.flat()
+    // Modules have the same shape as classes, so they get pages of their own
+    return [...classes, ...modules].map(separateCallbacks)
   },
 
   async contentLoaded({ content, actions: { addRoute, createData } }) {
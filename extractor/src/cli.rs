@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use crate::{compat::Compat, dialect::Dialect};
+
 #[derive(Debug, StructOpt)]
 #[structopt(about = env!("CARGO_PKG_DESCRIPTION"))]
 pub struct Args {
@@ -12,15 +14,125 @@ pub struct Args {
 #[derive(Debug, StructOpt)]
 pub enum Subcommand {
     Extract(ExtractSubcommand),
+    EmitLuals(EmitLualsSubcommand),
+    EmitSelene(EmitSeleneSubcommand),
 }
 
 /// Extracts doc comments from the given files
 #[derive(Debug, StructOpt)]
 pub struct ExtractSubcommand {
-    pub input_path: Option<PathBuf>,
+    /// The files and folders to extract from, which are documented together. If unspecified, the
+    /// current directory is used, unless `--stdin-filename` or `--files-from` is passed.
+    pub input_paths: Vec<PathBuf>,
 
     /// The base path that source paths in the output will be relative to.
-    /// If unspecified, the input path is used.
+    /// If unspecified, the input path is used, or the current directory if there are several.
     #[structopt(long = "base", short = "b")]
     pub base_path: Option<PathBuf>,
+
+    #[structopt(flatten)]
+    pub output: OutputOptions,
+
+    #[structopt(flatten)]
+    pub options: ExtractOptions,
+}
+
+// Options that change where and how the docs are written
+#[derive(Debug, Default, StructOpt)]
+pub struct OutputOptions {
+    /// Write the docs to this file instead of stdout. The file is only replaced once the docs have
+    /// been written in full, and is left alone if there are errors.
+    #[structopt(long, short, parse(from_os_str))]
+    pub output: Option<PathBuf>,
+
+    /// Write the docs as compact JSON, without indentation
+    #[structopt(long)]
+    pub compact: bool,
+}
+
+/// Writes lua-language-server `---@meta` stub files for the classes in the given files
+#[derive(Debug, StructOpt)]
+pub struct EmitLualsSubcommand {
+    /// The files and folders to extract from. If unspecified, the current directory is used.
+    pub input_paths: Vec<PathBuf>,
+
+    /// The directory to write a stub file for each class into. It's created if it doesn't exist.
+    #[structopt(long = "out-dir", short = "o")]
+    pub out_dir: PathBuf,
+
+    #[structopt(flatten)]
+    pub options: ExtractOptions,
+}
+
+/// Prints a selene standard library definition for the classes in the given files
+#[derive(Debug, StructOpt)]
+pub struct EmitSeleneSubcommand {
+    /// The files and folders to extract from. If unspecified, the current directory is used.
+    pub input_paths: Vec<PathBuf>,
+
+    /// The name of the standard library, which should match the name of the file it's written to
+    #[structopt(long, default_value = "moonwave")]
+    pub name: String,
+
+    #[structopt(flatten)]
+    pub options: ExtractOptions,
+}
+
+// Options that change what the extractor checks and outputs. This and `OutputOptions` aren't
+// documented with `///`, since structopt would show that as the description of every subcommand
+// they're flattened into.
+#[derive(Debug, Default, StructOpt)]
+pub struct ExtractOptions {
+    /// Warn about types and links in descriptions that don't refer to a documented class or type,
+    /// an external type, or a built-in type.
+    #[structopt(long)]
+    pub check_types: bool,
+
+    /// A type name that should be considered known when checking types. Can be passed more than
+    /// once.
+    #[structopt(long = "known-type", number_of_values = 1)]
+    pub known_types: Vec<String>,
+
+    /// A file of type names that should be considered known, one per line, like a list of Roblox
+    /// classes newer than the ones the extractor knows. Lines starting with `#` are ignored.
+    #[structopt(long, parse(from_os_str))]
+    pub known_types_file: Option<PathBuf>,
+
+    /// The dialect of Lua to parse source files and code blocks as: luau, lua51, lua52, lua53,
+    /// lua54, or luajit. Type annotations can only be read from Luau source.
+    #[structopt(long, default_value = "luau")]
+    pub dialect: Dialect,
+
+    /// Also read doc comments written for another tool. `emmylua` reads `---@param`, `---@return`,
+    /// `---@class` and `---@field` annotations, and `ldoc` reads LDoc comments. Can be passed more
+    /// than once.
+    #[structopt(long, number_of_values = 1)]
+    pub compat: Vec<Compat>,
+
+    /// A Rojo `*.project.json` file. Each class is given the DataModel path of the instance its
+    /// file is synced to, and classes in files outside the project get a warning.
+    #[structopt(long, parse(from_os_str))]
+    pub rojo_project: Option<PathBuf>,
+
+    /// Only extract files matching this glob, like `src/**`. Can be passed more than once.
+    #[structopt(long, number_of_values = 1)]
+    pub include: Vec<String>,
+
+    /// Skip files matching this glob, like `**/*.spec.lua`. Can be passed more than once. Files
+    /// listed in `.gitignore` and `.ignore` files are skipped as well.
+    #[structopt(long, number_of_values = 1)]
+    pub exclude: Vec<String>,
+
+    /// Read the source of a single file from stdin, documented as if it were at this path
+    #[structopt(long, parse(from_os_str))]
+    pub stdin_filename: Option<PathBuf>,
+
+    /// Extract the files listed in this file, one path per line, instead of walking a directory.
+    /// Paths are relative to the current directory.
+    #[structopt(long, parse(from_os_str))]
+    pub files_from: Option<PathBuf>,
+
+    /// List the files that were skipped by ignore files and globs
+    #[structopt(long, short)]
+    pub verbose: bool,
 }
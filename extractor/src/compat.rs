@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+/// Doc comment syntaxes from other tools that can be read alongside Moonwave's own tags
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compat {
+    /// `---@param`, `---@return`, `---@class` and `---@field` annotations used by EmmyLua and
+    /// lua-language-server
+    EmmyLua,
+    /// `-- @tparam`, `-- @treturn`, `-- @module` and `-- @field` tags used by LDoc, in comments
+    /// that start with `---`
+    LDoc,
+}
+
+impl FromStr for Compat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "emmylua" => Ok(Compat::EmmyLua),
+            "ldoc" => Ok(Compat::LDoc),
+            _ => Err(format!(
+                "Unknown compatibility mode \"{}\", expected emmylua or ldoc",
+                s
+            )),
+        }
+    }
+}
@@ -4,6 +4,14 @@ use crate::{doc_comment::DocComment, span::Span};
 use codespan_reporting::diagnostic::{Diagnostic as CodeSpanDiagnostic, Label};
 use serde::Serialize;
 
+/// Errors abort extraction, while warnings are reported without affecting the output
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
 #[derive(Debug, Default, Serialize)]
 pub struct Diagnostic {
     pub text: String,
@@ -11,6 +19,8 @@ pub struct Diagnostic {
     pub len: usize,
     pub file_id: usize,
     pub additional_diagnostics: Vec<Diagnostic>,
+    #[serde(skip)]
+    pub severity: Severity,
 }
 
 impl Diagnostic {
@@ -34,6 +44,17 @@ impl Diagnostic {
         }
     }
 
+    pub fn into_warning(self) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..self
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
     pub fn attach_diagnostic(&mut self, diagnostic: Diagnostic) -> &Self {
         self.additional_diagnostics.push(diagnostic);
 
@@ -68,7 +89,12 @@ impl From<Diagnostic> for CodeSpanDiagnostic<usize> {
             )
         }
 
-        CodeSpanDiagnostic::error()
+        let codespan_diagnostic = match diagnostic.severity {
+            Severity::Error => CodeSpanDiagnostic::error(),
+            Severity::Warning => CodeSpanDiagnostic::warning(),
+        };
+
+        codespan_diagnostic
             .with_message(&diagnostic.text)
             .with_labels(labels)
     }
@@ -0,0 +1,68 @@
+use std::str::FromStr;
+
+use full_moon::LuaVersion;
+
+/// The dialect of Lua that source files and code blocks are parsed as. Dialects other than Luau
+/// and Lua 5.1 need their cargo feature to be enabled.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    #[default]
+    Luau,
+    Lua51,
+    #[cfg(feature = "lua52")]
+    Lua52,
+    #[cfg(feature = "lua53")]
+    Lua53,
+    #[cfg(feature = "lua54")]
+    Lua54,
+    #[cfg(feature = "luajit")]
+    LuaJit,
+}
+
+const DIALECTS: &str = "luau, lua51, lua52, lua53, lua54, luajit";
+
+impl Dialect {
+    pub fn lua_version(self) -> LuaVersion {
+        match self {
+            Dialect::Luau => LuaVersion::luau(),
+            Dialect::Lua51 => LuaVersion::lua51(),
+            #[cfg(feature = "lua52")]
+            Dialect::Lua52 => LuaVersion::lua52(),
+            #[cfg(feature = "lua53")]
+            Dialect::Lua53 => LuaVersion::lua53(),
+            #[cfg(feature = "lua54")]
+            Dialect::Lua54 => LuaVersion::lua54(),
+            #[cfg(feature = "luajit")]
+            Dialect::LuaJit => LuaVersion::luajit(),
+        }
+    }
+}
+
+impl FromStr for Dialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "luau" => Ok(Dialect::Luau),
+            "lua51" => Ok(Dialect::Lua51),
+            #[cfg(feature = "lua52")]
+            "lua52" => Ok(Dialect::Lua52),
+            #[cfg(feature = "lua53")]
+            "lua53" => Ok(Dialect::Lua53),
+            #[cfg(feature = "lua54")]
+            "lua54" => Ok(Dialect::Lua54),
+            #[cfg(feature = "luajit")]
+            "luajit" => Ok(Dialect::LuaJit),
+            // Only reachable for dialects whose features are disabled
+            #[allow(unreachable_patterns)]
+            "lua52" | "lua53" | "lua54" | "luajit" => Err(format!(
+                "Moonwave was built without support for {}. Rebuild it with the \"{}\" feature",
+                s, s
+            )),
+            _ => Err(format!(
+                "Unknown dialect \"{}\", expected one of {}",
+                s, DIALECTS
+            )),
+        }
+    }
+}
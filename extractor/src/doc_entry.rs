@@ -2,25 +2,35 @@ use serde::Serialize;
 use std::convert::TryFrom;
 
 use crate::{
+    compat::Compat,
     diagnostic::{Diagnostic, Diagnostics},
     doc_comment::DocComment,
+    source_file::ModuleExports,
     span::Span,
-    tags::{validate_tags, Tag},
+    tags::{
+        is_example_tag, is_private_field, is_usage_tag, parse_emmylua_tag, parse_ldoc_tag,
+        take_example_block, take_usage_block, validate_tags, ExampleTag, FieldTag, PrivateTag,
+        PropertyTag, Tag,
+    },
 };
 use full_moon::{
-    ast::{self, punctuated::Punctuated, Stmt},
+    ast::{self, luau::TypeInfo, punctuated::Punctuated, Stmt},
     node::Node,
 };
 
 mod class;
+mod event;
 mod function;
 mod property;
 mod type_definition;
 
 pub use class::ClassDocEntry;
-pub use function::{FunctionDocEntry, FunctionType};
+pub use event::EventDocEntry;
+pub use function::{
+    FunctionDocEntry, FunctionParam, FunctionReturn, FunctionSignature, FunctionType,
+};
 pub use property::PropertyDocEntry;
-pub use type_definition::TypeDocEntry;
+pub use type_definition::{Field, TypeDocEntry};
 
 use self::function::FunctionSource;
 
@@ -41,9 +51,16 @@ enum DocEntryKind {
         within: String,
         name: String,
     },
+    Event {
+        within: String,
+        name: String,
+    },
     Class {
         name: String,
     },
+    Module {
+        name: String,
+    },
 }
 
 /// An enum of all possible DocEntries
@@ -54,6 +71,7 @@ pub enum DocEntry<'a> {
     Property(PropertyDocEntry<'a>),
     Class(ClassDocEntry<'a>),
     Type(TypeDocEntry<'a>),
+    Event(EventDocEntry<'a>),
 }
 
 #[derive(Debug)]
@@ -61,6 +79,8 @@ struct DocEntryParseArguments<'a> {
     name: String,
     tags: Vec<Tag<'a>>,
     desc: String,
+    /// The lines of the doc comment that `desc` was joined from
+    desc_spans: Vec<Span<'a>>,
     within: Option<String>,
     source: &'a DocComment,
 }
@@ -75,8 +95,10 @@ fn get_explicit_within(tags: &[Tag]) -> Option<String> {
     None
 }
 
+/// Splits a name like `Net.Server.Remote.fire` into the class it's within and its own name. The
+/// class is everything before the last `.`, so it can be nested at any depth.
 fn get_qualified_within(name: &str) -> Option<(String, String)> {
-    if let Some((first, second)) = name.split_once(".") {
+    if let Some((first, second)) = name.rsplit_once(".") {
         if !first.is_empty() && !second.is_empty() {
             return Some((first.to_owned(), second.to_owned()));
         }
@@ -109,6 +131,11 @@ fn get_explicit_kind(tags: &[Tag]) -> Result<Option<DocEntryKind>, Diagnostic> {
                     name: class_tag.name.as_str().to_owned(),
                 }))
             }
+            Tag::Module(module_tag) => {
+                return Ok(Some(DocEntryKind::Module {
+                    name: module_tag.name.as_str().to_owned(),
+                }))
+            }
             Tag::Function(function_tag) => {
                 let name = function_tag.name.as_str().to_owned();
                 let (within, name) = get_within_and_name(tags, tag, name)?;
@@ -119,11 +146,26 @@ fn get_explicit_kind(tags: &[Tag]) -> Result<Option<DocEntryKind>, Diagnostic> {
                     function_source: None,
                 }));
             }
+            Tag::Callback(callback_tag) => {
+                let name = callback_tag.name.as_str().to_owned();
+                let (within, name) = get_within_and_name(tags, tag, name)?;
+                return Ok(Some(DocEntryKind::Function {
+                    name,
+                    function_type: FunctionType::Static,
+                    within,
+                    function_source: None,
+                }));
+            }
             Tag::Property(property_tag) => {
                 let name = property_tag.name.as_str().to_owned();
                 let (within, name) = get_within_and_name(tags, tag, name)?;
                 return Ok(Some(DocEntryKind::Property { name, within }));
             }
+            Tag::Event(event_tag) => {
+                let name = event_tag.name.as_str().to_owned();
+                let (within, name) = get_within_and_name(tags, tag, name)?;
+                return Ok(Some(DocEntryKind::Event { name, within }));
+            }
             Tag::Type(type_tag) => {
                 let name = type_tag.name.as_str().to_owned();
                 let (within, name) = get_within_and_name(tags, tag, name)?;
@@ -157,7 +199,7 @@ where
                     start: start.bytes(),
                     len: end.bytes() - start.bytes(),
                     file_id: doc_comment.file_id,
-                    additional_diagnostics: vec![],
+                    ..Default::default()
                 }],
                 ..doc_comment.diagnostic(format!(
                     "Assignments cannot have more than one {token_kind}"
@@ -171,10 +213,62 @@ where
     Ok(())
 }
 
+/// Finds the function in an expression like `function() end` or `(function() end) :: T`, along
+/// with the type it is asserted to be, if any
+fn function_expression(
+    expression: &ast::Expression,
+) -> Option<(&ast::FunctionBody, Option<&TypeInfo>)> {
+    match expression {
+        ast::Expression::Function(function_box) => Some((&function_box.1, None)),
+        ast::Expression::Parentheses { expression, .. } => function_expression(expression),
+        ast::Expression::TypeAssertion {
+            expression,
+            type_assertion,
+        } => function_expression(expression)
+            .map(|(function_body, _)| (function_body, Some(type_assertion.cast_to()))),
+        _ => None,
+    }
+}
+
+fn function_source(
+    function_body: &ast::FunctionBody,
+    annotation: Option<&TypeInfo>,
+) -> FunctionSource {
+    annotation
+        .and_then(|annotation| FunctionSource::from_annotation(function_body, annotation))
+        .unwrap_or_else(|| function_body.clone().into())
+}
+
+/// The error for a local function without `@within`, which can only be inferred if the function
+/// is exported by the file
+fn unexported_local(doc_comment: &DocComment, exports: &ModuleExports) -> Diagnostic {
+    match &exports.owner {
+        Some(owner) => doc_comment.diagnostic(format!(
+            "Function requires @within tag, since it isn't exported through the table \"{}\" returns",
+            owner
+        )),
+        None => doc_comment.diagnostic("Function requires @within tag"),
+    }
+}
+
+/// Gets the table that an assignment like `Table.key = value` assigns into
+fn assignment_table(var: &ast::Var) -> Option<String> {
+    match var {
+        ast::Var::Expression(var_expression) => match var_expression.prefix() {
+            ast::Prefix::Name(name) if var_expression.suffixes().count() == 1 => {
+                Some(name.token().to_string())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn determine_kind(
     doc_comment: &DocComment,
     stmt: Option<&Stmt>,
     tags: &[Tag],
+    exports: &ModuleExports,
 ) -> Result<DocEntryKind, Diagnostic> {
     let explicit_kind = get_explicit_kind(tags)?;
 
@@ -195,12 +289,15 @@ fn determine_kind(
 
     match stmt {
         Some(Stmt::LocalFunction(function)) => {
-            let name = function.name().to_string();
+            let mut name = function.name().to_string();
 
             let within = if let Some(within) = within_tag {
                 within.name.as_str().to_owned()
+            } else if let Some((within, exported_name)) = exports.exported_local(&name) {
+                name = exported_name;
+                within
             } else {
-                return Err(doc_comment.diagnostic("Function requires @within tag"));
+                return Err(unexported_local(doc_comment, exports));
             };
 
             Ok(DocEntryKind::Function {
@@ -215,7 +312,8 @@ fn determine_kind(
                 let within = if let Some(within) = within_tag {
                     within.name.as_str().to_owned()
                 } else {
-                    function.name().names().to_string()
+                    let names = function.name().names().to_string();
+                    exports.qualify(&names).unwrap_or(names)
                 };
 
                 Ok(DocEntryKind::Function {
@@ -233,11 +331,13 @@ fn determine_kind(
                 let within = if let Some(within) = within_tag {
                     within.name.as_str().to_owned()
                 } else if !names.is_empty() {
-                    names
+                    let names = names
                         .into_iter()
                         .map(|token| token.to_string())
                         .collect::<Vec<_>>()
-                        .join(".")
+                        .join(".");
+
+                    exports.qualify(&names).unwrap_or(names)
                 } else {
                     return Err(doc_comment.diagnostic("Function requires @within tag"));
                 };
@@ -257,26 +357,34 @@ fn determine_kind(
             assert_token_sequence_is_single(variables, doc_comment, "variable")?;
             assert_token_sequence_is_single(expressions, doc_comment, "expression")?;
 
-            match expressions.first().unwrap().value() {
-                ast::Expression::Function(function_box) => {
-                    let function_body = &function_box.1;
+            match function_expression(expressions.first().unwrap().value()) {
+                Some((function_body, type_assertion)) => {
+                    let mut name = variables.first().unwrap().value().token().to_string();
 
                     let within = if let Some(within) = within_tag {
                         within.name.as_str().to_owned()
+                    } else if let Some((within, exported_name)) = exports.exported_local(&name) {
+                        name = exported_name;
+                        within
                     } else {
-                        return Err(doc_comment.diagnostic("Function requires @within tag"));
+                        return Err(unexported_local(doc_comment, exports));
                     };
 
-                    let name = variables.first().unwrap().value().token().to_string();
+                    let annotation = assignment
+                        .type_specifiers()
+                        .next()
+                        .flatten()
+                        .map(|specifier| specifier.type_info())
+                        .or(type_assertion);
 
                     Ok(DocEntryKind::Function {
                         name,
                         within,
                         function_type: FunctionType::Static,
-                        function_source: Some(function_body.clone().into()),
+                        function_source: Some(function_source(function_body, annotation)),
                     })
                 }
-                _ => Err(doc_comment.diagnostic("Expression must be a function")),
+                None => Err(doc_comment.diagnostic("Expression must be a function")),
             }
         }
         Some(Stmt::Assignment(assignment)) => {
@@ -286,12 +394,18 @@ fn determine_kind(
             assert_token_sequence_is_single(variables, doc_comment, "variable")?;
             assert_token_sequence_is_single(expressions, doc_comment, "expression")?;
 
-            match expressions.into_iter().next().unwrap() {
-                ast::Expression::Function(function_box) => {
-                    let function_body = &function_box.1;
+            match function_expression(expressions.into_iter().next().unwrap()) {
+                Some((function_body, type_assertion)) => {
+                    let table = variables
+                        .iter()
+                        .next()
+                        .and_then(assignment_table)
+                        .and_then(|table| exports.qualify(&table));
 
                     let within = if let Some(within) = within_tag {
                         within.name.as_str().to_owned()
+                    } else if let Some(table) = table {
+                        table
                     } else {
                         return Err(doc_comment.diagnostic("Function requires @within tag"));
                     };
@@ -326,10 +440,10 @@ fn determine_kind(
                         name: name.unwrap(),
                         within,
                         function_type: FunctionType::Static,
-                        function_source: Some(function_body.clone().into()),
+                        function_source: Some(function_source(function_body, type_assertion)),
                     })
                 }
-                _ => Err(doc_comment.diagnostic("Expression must be a function")),
+                None => Err(doc_comment.diagnostic("Expression must be a function")),
             }
         }
 
@@ -338,70 +452,201 @@ fn determine_kind(
     }
 }
 
-impl<'a> DocEntry<'a> {
-    pub fn parse(doc_comment: &'a DocComment) -> Result<(DocEntry<'a>, Vec<Tag<'a>>), Diagnostics> {
-        let stmt = doc_comment.stmt.as_ref();
+/// Whether a `---` doc comment is written with EmmyLua annotations like `---@param`
+fn is_emmylua_comment(span: Span) -> bool {
+    span.lines().all(|line| line.starts_with("---"))
+        && span.lines().any(|line| line.starts_with("---@"))
+}
 
-        let span: Span<'a> = doc_comment.into();
+/// Removes the `---` from each line of an EmmyLua doc comment, along with the space that usually
+/// follows it in descriptions
+fn emmylua_lines(span: Span) -> Vec<Span> {
+    span.lines()
+        .filter(|line| line.as_str() != "---")
+        .map(|line| {
+            let line = line.strip_prefix("---").unwrap_or(line);
+            line.strip_prefix(" ").unwrap_or(line)
+        })
+        .collect()
+}
+
+/// LDoc tags that Moonwave doesn't have, which show that a `---` comment is written for LDoc
+const LDOC_TAGS: &[&str] = &[
+    "@tparam",
+    "@treturn",
+    "@tfield",
+    "@module",
+    "@classmod",
+    "@usage",
+];
+
+/// Whether a doc comment is written for LDoc, which starts with `---` and continues with `--`
+fn is_ldoc_comment(span: Span) -> bool {
+    let mut lines = span.lines();
+
+    lines.next().is_some_and(|line| line.starts_with("---"))
+        && (lines.any(|line| !line.starts_with("---"))
+            || ldoc_lines(span)
+                .iter()
+                .any(|line| LDOC_TAGS.iter().any(|tag| line.starts_with(tag))))
+}
 
-        let mut lines = span.lines();
+/// Removes the `---` or `--` from each line of an LDoc comment, along with the space after it
+fn ldoc_lines(span: Span) -> Vec<Span> {
+    span.lines()
+        .filter(|line| line.as_str() != "---")
+        .map(|line| {
+            let line = line
+                .strip_prefix("---")
+                .or_else(|| line.strip_prefix("--"))
+                .unwrap_or(line);
+            line.strip_prefix(" ").unwrap_or(line)
+        })
+        .collect()
+}
 
-        let first_line = lines.next();
+/// Removes the indentation from each line of a doc comment, which must be the same on every line
+fn moonwave_lines(span: Span) -> Result<Vec<Span>, Diagnostics> {
+    let mut lines = span.lines();
 
-        let mut indentation = None;
+    let first_line = lines.next();
 
-        if let Some(first_line) = first_line {
-            if first_line.starts_with("---") {
-                if first_line.len() == 3 {
-                    return Err(Diagnostics::from(vec![span.diagnostic(
-                        "The first line of a doc comment must have text after the triple dash",
-                    )]));
-                }
+    let mut indentation = None;
 
-                indentation = Some(&first_line.as_str()[..4]);
-            } else if first_line.contains(|char: char| !char.is_whitespace()) {
-                return Err(Diagnostics::from(vec![
-                    span.diagnostic("There must be a new line after --[=[")
-                ]));
+    if let Some(first_line) = first_line {
+        if first_line.starts_with("---") {
+            if first_line.len() == 3 {
+                return Err(Diagnostics::from(vec![span.diagnostic(
+                    "The first line of a doc comment must have text after the triple dash",
+                )]));
             }
+
+            indentation = Some(&first_line.as_str()[..4]);
+        } else if first_line.contains(|char: char| !char.is_whitespace()) {
+            return Err(Diagnostics::from(vec![
+                span.diagnostic("There must be a new line after --[=[")
+            ]));
         }
+    }
+
+    let indentation = indentation.unwrap_or_else(|| {
+        lines
+            .find(|span| span.contains(|char: char| !char.is_whitespace()))
+            .map(|span| span.as_str())
+            .and_then(|str| {
+                let first_non_whitespace = str.find(|char: char| !char.is_whitespace())?;
 
-        let indentation = indentation.unwrap_or_else(|| {
-            lines
-                .find(|span| span.contains(|char: char| !char.is_whitespace()))
-                .map(|span| span.as_str())
-                .and_then(|str| {
-                    let first_non_whitespace = str.find(|char: char| !char.is_whitespace())?;
+                Some(&str[..first_non_whitespace])
+            })
+            .unwrap_or("")
+    });
+
+    if !span.lines().all(|span| {
+        span.is_empty()
+            || span.starts_with(indentation)
+            || span.as_str() == "---"
+            || span.chars().all(char::is_whitespace)
+    }) {
+        return Err(Diagnostics::from(vec![span.diagnostic(
+            "This doc comment has mixed indentation. \
+            All lines within the doc comment must start with the same indentation. \
+            Try using your editor's \"Convert Indentation to Tabs\" code action.",
+        )]));
+    }
 
-                    Some(&str[..first_non_whitespace])
-                })
-                .unwrap_or("")
-        });
+    Ok(span
+        .lines()
+        .filter(|span| span.as_str() != "---")
+        .map(|span| span.strip_prefix(indentation).unwrap_or(span))
+        .collect())
+}
 
-        if !span.lines().all(|span| {
-            span.is_empty()
-                || span.starts_with(indentation)
-                || span.as_str() == "---"
-                || span.chars().all(char::is_whitespace)
-        }) {
-            return Err(Diagnostics::from(vec![span.diagnostic(
-                "This doc comment has mixed indentation. \
-                All lines within the doc comment must start with the same indentation. \
-                Try using your editor's \"Convert Indentation to Tabs\" code action.",
-            )]));
-        }
+/// Turns a `@field` from EmmyLua or LDoc on a class into a property within it
+fn emmylua_property<'a>(
+    field: FieldTag<'a>,
+    within: &str,
+    doc_comment: &'a DocComment,
+) -> Result<DocEntry<'a>, Diagnostics> {
+    let mut tags = vec![Tag::Property(PropertyTag {
+        name: field.name,
+        lua_type: field.lua_type,
+        translated_type: field.translated_type.clone(),
+        source: field.source,
+    })];
+
+    if is_private_field(&field) {
+        tags.push(Tag::Private(PrivateTag {
+            source: field.source,
+        }));
+    }
 
-        let (tag_lines, desc_lines): (Vec<Span>, Vec<Span>) = span
-            .lines()
-            .filter(|span| span.as_str() != "---")
-            .map(|span| span.strip_prefix(indentation).unwrap_or(span))
-            .partition(|line| line.starts_with(&['@', '.'][..]));
+    let desc_spans = if field.desc.is_empty() {
+        Vec::new()
+    } else {
+        vec![field.desc]
+    };
+
+    Ok(DocEntry::Property(PropertyDocEntry::parse(
+        DocEntryParseArguments {
+            within: Some(within.to_owned()),
+            name: field.name.as_str().to_owned(),
+            desc: field.desc.as_str().to_owned(),
+            desc_spans,
+            tags,
+            source: doc_comment,
+        },
+    )?))
+}
 
-        let mut desc_lines = desc_lines
-            .into_iter()
-            .skip_while(|line| line.is_empty())
-            .map(|span| span.as_str())
-            .collect::<Vec<_>>();
+impl<'a> DocEntry<'a> {
+    pub fn parse(
+        doc_comment: &'a DocComment,
+        exports: &ModuleExports,
+        compat: &[Compat],
+    ) -> Result<(Vec<DocEntry<'a>>, Vec<Tag<'a>>), Diagnostics> {
+        let stmt = doc_comment.stmt.as_ref();
+
+        let span: Span<'a> = doc_comment.into();
+
+        let emmylua = compat.contains(&Compat::EmmyLua) && is_emmylua_comment(span);
+        let ldoc = !emmylua && compat.contains(&Compat::LDoc) && is_ldoc_comment(span);
+
+        let lines = if emmylua {
+            emmylua_lines(span)
+        } else if ldoc {
+            ldoc_lines(span)
+        } else {
+            moonwave_lines(span)?
+        };
+
+        let mut lines = lines.into_iter().peekable();
+
+        let mut tag_results = Vec::new();
+        let mut desc_lines = Vec::new();
+
+        while let Some(line) = lines.next() {
+            if is_example_tag(&line) {
+                // Examples span multiple lines, so they're parsed as the lines are read
+                let block = take_example_block(&mut lines);
+                tag_results.push(ExampleTag::parse(line, block).map(Tag::Example));
+            } else if ldoc && is_usage_tag(&line) {
+                let block = take_usage_block(line, &mut lines);
+                tag_results.push(ExampleTag::parse(line, block).map(Tag::Example));
+            } else if emmylua && line.starts_with('@') {
+                tag_results.extend(parse_emmylua_tag(line));
+            } else if ldoc && line.starts_with('@') {
+                tag_results.extend(parse_ldoc_tag(line));
+            } else if line.starts_with(&['@', '.'][..]) {
+                tag_results.push(Tag::try_from(line));
+            } else if !desc_lines.is_empty() || !line.is_empty() {
+                desc_lines.push(line);
+            }
+        }
+
+        // Comments with only annotations for the language server or LDoc don't document anything
+        if (emmylua || ldoc) && tag_results.is_empty() && desc_lines.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
+        }
 
         while let Some(line) = desc_lines.last() {
             if !line.is_empty() {
@@ -411,12 +656,13 @@ impl<'a> DocEntry<'a> {
             desc_lines.pop();
         }
 
-        let desc = desc_lines.join("\n");
+        let desc = desc_lines
+            .iter()
+            .map(Span::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
 
-        let (tags, errors): (Vec<_>, Vec<_>) = tag_lines
-            .into_iter()
-            .map(Tag::try_from)
-            .partition(Result::is_ok);
+        let (tags, errors): (Vec<_>, Vec<_>) = tag_results.into_iter().partition(Result::is_ok);
 
         let mut tags: Vec<_> = tags.into_iter().map(Result::unwrap).collect();
         let mut errors: Vec<_> = errors.into_iter().map(Result::unwrap_err).collect();
@@ -429,67 +675,116 @@ impl<'a> DocEntry<'a> {
             return Err(Diagnostics::from(errors));
         }
 
-        let kind =
-            determine_kind(doc_comment, stmt, &tags).map_err(|err| Diagnostics::from(vec![err]))?;
+        let kind = determine_kind(doc_comment, stmt, &tags, exports)
+            .map_err(|err| Diagnostics::from(vec![err]))?;
 
         // Sift out the kind/within tags because those are only used for determining the kind
         tags.retain(|t| {
             !matches!(
                 t,
-                Tag::Function(_) | Tag::Within(_) | Tag::Class(_) | Tag::Interface(_)
+                Tag::Function(_)
+                    | Tag::Within(_)
+                    | Tag::Class(_)
+                    | Tag::Module(_)
+                    | Tag::Event(_)
+                    | Tag::Interface(_)
             )
         });
 
-        Ok(match kind {
+        // EmmyLua and LDoc document the properties of a class with `@field` in the class's doc
+        // comment
+        let properties = match &kind {
+            DocEntryKind::Class { name } | DocEntryKind::Module { name } if emmylua || ldoc => {
+                let (fields, rest): (Vec<_>, Vec<_>) = tags
+                    .into_iter()
+                    .partition(|tag| matches!(tag, Tag::Field(_)));
+                tags = rest;
+
+                fields
+                    .into_iter()
+                    .filter_map(|tag| match tag {
+                        Tag::Field(field) => Some(emmylua_property(field, name, doc_comment)),
+                        _ => None,
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            }
+            _ => Vec::new(),
+        };
+
+        let entry = match kind {
             DocEntryKind::Function {
                 within,
                 name,
                 function_type,
                 function_source,
-            } => (
-                DocEntry::Function(FunctionDocEntry::parse(
-                    DocEntryParseArguments {
-                        within: Some(within),
-                        name,
-                        desc,
-                        tags,
-                        source: doc_comment,
-                    },
-                    function_type,
-                    function_source,
-                )?),
-                all_tags,
-            ),
-            DocEntryKind::Property { within, name } => (
+            } => DocEntry::Function(FunctionDocEntry::parse(
+                DocEntryParseArguments {
+                    within: Some(within),
+                    name,
+                    desc,
+                    desc_spans: desc_lines,
+                    tags,
+                    source: doc_comment,
+                },
+                function_type,
+                function_source,
+            )?),
+            DocEntryKind::Property { within, name } => {
                 DocEntry::Property(PropertyDocEntry::parse(DocEntryParseArguments {
                     within: Some(within),
                     name,
                     desc,
+                    desc_spans: desc_lines,
                     tags,
                     source: doc_comment,
-                })?),
-                all_tags,
-            ),
-            DocEntryKind::Type { within, name } => (
+                })?)
+            }
+            DocEntryKind::Type { within, name } => {
                 DocEntry::Type(TypeDocEntry::parse(DocEntryParseArguments {
                     within: Some(within),
                     name,
                     desc,
+                    desc_spans: desc_lines,
+                    tags,
+                    source: doc_comment,
+                })?)
+            }
+            DocEntryKind::Event { within, name } => {
+                DocEntry::Event(EventDocEntry::parse(DocEntryParseArguments {
+                    within: Some(within),
+                    name,
+                    desc,
+                    desc_spans: desc_lines,
                     tags,
                     source: doc_comment,
-                })?),
-                all_tags,
-            ),
-            DocEntryKind::Class { name } => (
+                })?)
+            }
+            DocEntryKind::Class { name } => {
                 DocEntry::Class(ClassDocEntry::parse(DocEntryParseArguments {
                     within: None,
                     name,
                     desc,
+                    desc_spans: desc_lines,
                     tags,
                     source: doc_comment,
-                })?),
-                all_tags,
-            ),
-        })
+                })?)
+            }
+            DocEntryKind::Module { name } => DocEntry::Class(ClassDocEntry {
+                module: true,
+                ..ClassDocEntry::parse(DocEntryParseArguments {
+                    within: None,
+                    name,
+                    desc,
+                    desc_spans: desc_lines,
+                    tags,
+                    source: doc_comment,
+                })?
+            }),
+        };
+
+        let mut entries = vec![entry];
+        entries.extend(properties);
+
+        Ok((entries, all_tags))
     }
 }
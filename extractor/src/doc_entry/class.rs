@@ -1,11 +1,12 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
     diagnostic::Diagnostics,
     doc_comment::{DocComment, OutputSource},
     realm::Realm,
     serde_util::is_false,
-    tags::{CustomTag, DeprecatedTag, ExternalTag, Tag},
+    span::Span,
+    tags::{CustomTag, DeprecatedTag, ExampleTag, ExternalTag, Tag},
 };
 use serde::Serialize;
 
@@ -21,10 +22,24 @@ pub struct ClassDocEntry<'a> {
     pub tags: Vec<CustomTag<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub external_types: Vec<ExternalTag<'a>>,
+    /// Where the references in this entry's descriptions link to, like `Class#method`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ExampleTag<'a>>,
     #[serde(skip_serializing_if = "BTreeSet::is_empty")]
     pub realm: BTreeSet<Realm>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<DeprecatedTag<'a>>,
+    /// Whether this was documented with `@module` rather than `@class`
+    #[serde(skip)]
+    pub module: bool,
+    /// The parent class set with `@extends`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<Span<'a>>,
+    /// The interfaces set with `@implements`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<Span<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub since: Option<String>,
     #[serde(skip_serializing_if = "is_false")]
@@ -36,10 +51,16 @@ pub struct ClassDocEntry<'a> {
 
     #[serde(rename = "source")]
     pub output_source: OutputSource,
+    /// The instance this class's file is synced to by Rojo, like `ReplicatedStorage.Packages.Fabric`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_model_path: Option<String>,
 
     #[serde(skip)]
     pub __index: String,
 
+    /// The lines of the doc comment that `desc` was joined from
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
     #[serde(skip)]
     pub source: &'a DocComment,
 }
@@ -49,6 +70,7 @@ impl<'a> ClassDocEntry<'a> {
         let DocEntryParseArguments {
             name,
             desc,
+            desc_spans,
             within: _,
             tags,
             source,
@@ -57,6 +79,9 @@ impl<'a> ClassDocEntry<'a> {
         let mut doc_entry = Self {
             name,
             desc,
+            desc_spans,
+            links: BTreeMap::new(),
+            examples: Vec::new(),
             source,
             tags: Vec::new(),
             external_types: Vec::new(),
@@ -65,8 +90,12 @@ impl<'a> ClassDocEntry<'a> {
             unreleased: false,
             ignore: false,
             deprecated: None,
+            module: false,
+            extends: None,
+            implements: Vec::new(),
             since: None,
             output_source: source.output_source.clone(),
+            data_model_path: None,
             __index: "__index".to_owned(),
         };
 
@@ -76,9 +105,12 @@ impl<'a> ClassDocEntry<'a> {
             match tag {
                 Tag::Custom(tag) => doc_entry.tags.push(tag),
                 Tag::External(external_tag) => doc_entry.external_types.push(external_tag),
+                Tag::Example(example_tag) => doc_entry.examples.push(example_tag),
                 Tag::Deprecated(deprecated_tag) => doc_entry.deprecated = Some(deprecated_tag),
                 Tag::Since(since_tag) => doc_entry.since = Some(since_tag.version.to_string()),
                 Tag::Index(index_tag) => doc_entry.__index = index_tag.name.to_string(),
+                Tag::Extends(extends_tag) => doc_entry.extends = Some(extends_tag.name),
+                Tag::Implements(implements_tag) => doc_entry.implements.push(implements_tag.name),
 
                 Tag::Private(_) => doc_entry.private = true,
                 Tag::Unreleased(_) => doc_entry.unreleased = true,
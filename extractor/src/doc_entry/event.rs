@@ -0,0 +1,147 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    diagnostic::{Diagnostic, Diagnostics},
+    doc_comment::{DocComment, OutputSource},
+    realm::Realm,
+    serde_util::is_false,
+    span::Span,
+    tags::{CustomTag, DeprecatedTag, ExampleTag, ExternalTag, Tag},
+};
+use serde::Serialize;
+
+use super::{function::FunctionParam, DocEntryParseArguments};
+
+/// A DocEntry for an event or signal that a class fires.
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct EventDocEntry<'a> {
+    pub name: String,
+    pub desc: String,
+    /// The arguments that connected callbacks are called with
+    pub params: Vec<FunctionParam<'a>>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<CustomTag<'a>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub external_types: Vec<ExternalTag<'a>>,
+    /// Where the references in this entry's descriptions link to, like `Class#method`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ExampleTag<'a>>,
+    /// The ancestor class this member was copied from, set when resolving `@extends`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<String>,
+    #[serde(skip_serializing_if = "BTreeSet::is_empty")]
+    pub realm: BTreeSet<Realm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecated: Option<DeprecatedTag<'a>>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub private: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub unreleased: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub ignore: bool,
+
+    #[serde(rename = "source")]
+    pub output_source: OutputSource,
+
+    /// The lines of the doc comment that `desc` was joined from
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
+    #[serde(skip)]
+    pub source: &'a DocComment,
+    #[serde(skip)]
+    pub within: String,
+}
+
+impl<'a> EventDocEntry<'a> {
+    pub(super) fn parse(args: DocEntryParseArguments<'a>) -> Result<Self, Diagnostics> {
+        let DocEntryParseArguments {
+            name,
+            desc,
+            desc_spans,
+            within,
+            tags,
+            source,
+        } = args;
+
+        let mut doc_entry = Self {
+            name,
+            desc,
+            desc_spans,
+            params: Vec::new(),
+            links: BTreeMap::new(),
+            examples: Vec::new(),
+            inherited_from: None,
+            source,
+            since: None,
+            deprecated: None,
+            within: within.unwrap(),
+            tags: Vec::new(),
+            external_types: Vec::new(),
+            realm: BTreeSet::new(),
+            private: false,
+            unreleased: false,
+            ignore: false,
+            output_source: source.output_source.clone(),
+        };
+
+        let mut unused_tags = Vec::new();
+
+        for tag in tags {
+            match tag {
+                Tag::Param(param) => doc_entry.params.push(param.into()),
+
+                Tag::Deprecated(deprecated_tag) => doc_entry.deprecated = Some(deprecated_tag),
+                Tag::Since(since_tag) => doc_entry.since = Some(since_tag.version.to_string()),
+                Tag::Custom(custom_tag) => doc_entry.tags.push(custom_tag),
+                Tag::External(external_tag) => doc_entry.external_types.push(external_tag),
+                Tag::Example(example_tag) => doc_entry.examples.push(example_tag),
+
+                Tag::Private(_) => doc_entry.private = true,
+                Tag::Unreleased(_) => doc_entry.unreleased = true,
+                Tag::Ignore(_) => doc_entry.ignore = true,
+
+                Tag::Server(_) => {
+                    doc_entry.realm.insert(Realm::Server);
+                }
+                Tag::Client(_) => {
+                    doc_entry.realm.insert(Realm::Client);
+                }
+                Tag::Plugin(_) => {
+                    doc_entry.realm.insert(Realm::Plugin);
+                }
+                _ => unused_tags.push(tag),
+            }
+        }
+
+        doc_entry
+            .params
+            .iter_mut()
+            .for_each(FunctionParam::infer_flags);
+
+        let mut diagnostics = Vec::new();
+
+        for param in &doc_entry.params {
+            if param.lua_type.is_empty() {
+                diagnostics.push(Diagnostic::from_doc_comment(
+                    format!("Event argument \"{}\" has no type", param.name),
+                    source,
+                ))
+            }
+        }
+
+        for tag in unused_tags {
+            diagnostics.push(tag.diagnostic("This tag is unused by event doc entries."));
+        }
+
+        if !diagnostics.is_empty() {
+            return Err(Diagnostics::from(diagnostics));
+        }
+
+        Ok(doc_entry)
+    }
+}
@@ -1,13 +1,24 @@
-use std::collections::BTreeSet;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter,
+};
 
 use crate::{
     diagnostic::{Diagnostic, Diagnostics},
     doc_comment::{DocComment, OutputSource},
     realm::Realm,
     serde_util::is_false,
-    tags::{CustomTag, DeprecatedTag, ErrorTag, ExternalTag, ParamTag, ReturnTag, Tag},
+    span::Span,
+    tags::{
+        luau_type, CustomTag, DeprecatedTag, ErrorTag, ExampleTag, ExternalTag, ParamTag,
+        ReturnTag, Tag,
+    },
+    type_ast::TypeAst,
+};
+use full_moon::ast::{
+    luau::{GenericDeclaration, GenericParameterInfo, TypeArgument, TypeInfo},
+    FunctionBody, Parameter,
 };
-use full_moon::ast::{luau::TypeInfo::Tuple, FunctionBody};
 use serde::Serialize;
 
 use super::DocEntryParseArguments;
@@ -22,111 +33,360 @@ pub enum FunctionType {
 
 #[derive(Debug, PartialEq, Serialize, Clone)]
 pub struct FunctionSource {
-    params: Vec<FunctionParam>,
-    returns: Vec<FunctionReturn>,
+    params: Vec<FunctionParam<'static>>,
+    returns: Vec<FunctionReturn<'static>>,
+    /// Additional signatures declared by an intersection type annotation, like
+    /// `((A) -> B) & ((C) -> D)`
+    overloads: Vec<FunctionSource>,
+    /// The type variables the function declares, like `T` in `function map<T>(value: T)`
+    generics: Vec<String>,
+}
+
+fn generic_names(generics: Option<&GenericDeclaration>) -> Vec<String> {
+    generics
+        .into_iter()
+        .flat_map(|declaration| declaration.generics())
+        .map(|generic| match generic.parameter() {
+            GenericParameterInfo::Name(name) => name.token().to_string(),
+            GenericParameterInfo::Variadic { name, .. } => name.token().to_string(),
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+fn parameter_name(parameter: &Parameter) -> String {
+    match parameter {
+        Parameter::Ellipsis(_) => "...".to_owned(),
+        Parameter::Name(token) => {
+            if let full_moon::tokenizer::TokenType::Identifier { identifier } = token.token_type() {
+                identifier.to_string()
+            } else {
+                unreachable!()
+            }
+        }
+        _ => {
+            unreachable!()
+        }
+    }
+}
+
+fn returns_from_type(info: &TypeInfo) -> Vec<FunctionReturn<'static>> {
+    match info {
+        TypeInfo::Tuple { types, .. } => types
+            .into_iter()
+            .map(|ty| FunctionReturn::new(ty.to_string()))
+            .collect::<Vec<_>>(),
+        _ => vec![FunctionReturn::new(info.to_string())],
+    }
+}
+
+/// Strips redundant parentheses, like the ones in `((A) -> B) & ((C) -> D)`
+fn unwrap_parentheses(info: &TypeInfo) -> &TypeInfo {
+    match info {
+        TypeInfo::Tuple { types, .. } if types.len() == 1 => {
+            unwrap_parentheses(types.iter().next().unwrap())
+        }
+        _ => info,
+    }
+}
+
+impl FunctionSource {
+    /// Creates a FunctionSource from a callback or intersection-of-callbacks type annotation that
+    /// is attached to a function expression. Parameters that aren't named in the annotation take
+    /// their name from the function body.
+    pub fn from_annotation(func: &FunctionBody, annotation: &TypeInfo) -> Option<Self> {
+        let callbacks: Vec<&TypeInfo> = match unwrap_parentheses(annotation) {
+            TypeInfo::Intersection(intersection) => intersection
+                .types()
+                .iter()
+                .map(unwrap_parentheses)
+                .collect(),
+            info => vec![info],
+        };
+
+        let mut signatures = callbacks
+            .into_iter()
+            .map(|callback| match callback {
+                TypeInfo::Callback {
+                    generics,
+                    arguments,
+                    return_type,
+                    ..
+                } => Some(Self {
+                    generics: generic_names(generics.as_ref()),
+                    ..Self::from_callback(func, arguments.iter(), return_type)
+                }),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter();
+
+        let mut primary = signatures.next()?;
+        primary.overloads = signatures.collect();
+
+        // Overloads share the type variables of the function, as do the ones it declares itself
+        let overload_generics = primary
+            .overloads
+            .iter()
+            .flat_map(|overload| &overload.generics);
+        let mut generics = generic_names(func.generics());
+        for generic in primary.generics.iter().chain(overload_generics) {
+            if !generics.contains(generic) {
+                generics.push(generic.clone());
+            }
+        }
+        primary.generics = generics;
+
+        Some(primary)
+    }
+
+    fn from_callback<'b>(
+        func: &FunctionBody,
+        arguments: impl Iterator<Item = &'b TypeArgument>,
+        return_type: &TypeInfo,
+    ) -> Self {
+        let body_names: Vec<String> = func.parameters().iter().map(parameter_name).collect();
+
+        let params = arguments
+            .enumerate()
+            .map(|(index, argument)| {
+                let name = match (argument.name(), argument.type_info()) {
+                    (Some((name, _)), _) => name.token().to_string(),
+                    (None, TypeInfo::Variadic { .. } | TypeInfo::VariadicPack { .. }) => {
+                        "...".to_owned()
+                    }
+                    (None, _) => body_names
+                        .get(index)
+                        .cloned()
+                        .unwrap_or_else(|| format!("arg{}", index + 1)),
+                };
+
+                let lua_type = match argument.type_info() {
+                    TypeInfo::Variadic { type_info, .. } if name == "..." => type_info.to_string(),
+                    info => info.to_string(),
+                };
+
+                FunctionParam::new(name, lua_type)
+            })
+            .collect();
+
+        FunctionSource {
+            params,
+            returns: returns_from_type(return_type),
+            overloads: Vec::new(),
+            generics: Vec::new(),
+        }
+    }
 }
 
 impl From<FunctionBody> for FunctionSource {
     fn from(func: FunctionBody) -> Self {
         let mut params = Vec::new();
 
-        let params_and_types = func.parameters().into_iter().zip(func.type_specifiers());
+        // Dialects without type annotations may not have a type specifier for every parameter
+        let type_specifiers = func.type_specifiers().chain(iter::repeat(None));
+        let params_and_types = func.parameters().into_iter().zip(type_specifiers);
         for (parameter, type_specifier) in params_and_types {
-            let source_param = FunctionParam {
-                name: match parameter {
-                    full_moon::ast::Parameter::Ellipsis(_) => "...".to_owned(),
-                    full_moon::ast::Parameter::Name(token) => {
-                        if let full_moon::tokenizer::TokenType::Identifier { identifier } =
-                            token.token_type()
-                        {
-                            identifier.to_string()
-                        } else {
-                            unreachable!()
-                        }
-                    }
-                    _ => {
-                        unreachable!()
-                    }
-                },
-                desc: "".to_string(),
-                lua_type: type_specifier
+            let source_param = FunctionParam::new(
+                parameter_name(parameter),
+                type_specifier
                     .map(|type_specifier| type_specifier.type_info().to_string())
                     .unwrap_or_else(String::new),
-            };
+            );
 
             params.push(source_param);
         }
 
         let returns = match func.return_type() {
-            Some(return_type) => {
-                let info = return_type.type_info();
-
-                match info {
-                    Tuple { types, .. } => types
-                        .into_iter()
-                        .map(|ty| FunctionReturn {
-                            lua_type: ty.to_string(),
-                            desc: String::new(),
-                        })
-                        .collect::<Vec<_>>(),
-                    _ => vec![FunctionReturn {
-                        lua_type: info.to_string(),
-                        desc: String::new(),
-                    }],
-                }
-            }
+            Some(return_type) => returns_from_type(return_type.type_info()),
             None => Vec::new(),
         };
 
-        FunctionSource { params, returns }
+        FunctionSource {
+            params,
+            returns,
+            overloads: Vec::new(),
+            generics: generic_names(func.generics()),
+        }
     }
 }
 
 #[derive(Debug, PartialEq, Serialize, Clone)]
-pub struct FunctionParam {
-    name: String,
-    desc: String,
-    lua_type: String,
+pub struct FunctionParam<'a> {
+    pub name: String,
+    pub desc: String,
+    pub lua_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ast: Option<TypeAst>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub optional: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub variadic: bool,
+
+    /// The `@param` tag this parameter was documented with, if any
+    #[serde(skip)]
+    pub tag_source: Option<Span<'a>>,
+    /// The Luau annotation of this parameter, if the `@param` tag replaced it with its own type
+    #[serde(skip)]
+    pub annotated_type: Option<String>,
+}
+
+impl FunctionParam<'_> {
+    fn new(name: String, lua_type: String) -> Self {
+        Self {
+            name,
+            lua_type,
+            desc: String::new(),
+            type_ast: None,
+            optional: false,
+            default: None,
+            variadic: false,
+            tag_source: None,
+            annotated_type: None,
+        }
+    }
+
+    /// Fills in `optional` and `variadic` from the spelling of the name and type, like `name?`,
+    /// `T?`, `T | nil`, or `...`
+    pub(super) fn infer_flags(&mut self) {
+        self.variadic = self.name.trim_end_matches('?') == "...";
+        self.optional = self.optional
+            || self.name.ends_with('?')
+            || self.default.is_some()
+            || is_optional_type(&self.lua_type);
+    }
+}
+
+/// Splits a type on the given separator, ignoring separators nested in brackets
+fn split_top_level<'s>(lua_type: &'s str, separator: &str) -> Vec<&'s str> {
+    let mut pieces = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (index, char) in lua_type.char_indices() {
+        match char {
+            '(' | '{' | '[' | '<' => depth += 1,
+            ')' | '}' | ']' => depth -= 1,
+            // Don't mistake the arrow in `->` for a closing bracket
+            '>' if !lua_type[..index].ends_with('-') => depth -= 1,
+            _ if depth == 0 && lua_type[index..].starts_with(separator) => {
+                pieces.push(&lua_type[start..index]);
+                start = index + separator.len();
+            }
+            _ => {}
+        }
+    }
+
+    pieces.push(&lua_type[start..]);
+    pieces
+}
+
+/// Whether a type accepts `nil`, like `T?` or `T | nil`. The `?` of a function type's return
+/// value, like `() -> T?`, doesn't count.
+fn is_optional_type(lua_type: &str) -> bool {
+    let lua_type = lua_type.trim();
+
+    if split_top_level(lua_type, "|")
+        .into_iter()
+        .any(|piece| piece.trim() == "nil")
+    {
+        return true;
+    }
+
+    lua_type.ends_with('?') && split_top_level(lua_type, "->").len() == 1
 }
 
-impl<'a> From<ParamTag<'a>> for FunctionParam {
-    fn from(tag: ParamTag) -> Self {
+impl<'a> From<ParamTag<'a>> for FunctionParam<'a> {
+    fn from(tag: ParamTag<'a>) -> Self {
         Self {
-            name: tag.name.to_string(),
             desc: tag.desc.to_string(),
-            lua_type: tag.lua_type.to_string(),
+            default: tag.default.map(|default| default.to_string()),
+            optional: tag.optional,
+            tag_source: Some(tag.source),
+            ..Self::new(
+                tag.name.to_string(),
+                luau_type(tag.lua_type, &tag.translated_type),
+            )
         }
     }
 }
 
 #[derive(Debug, PartialEq, Serialize, Clone)]
-pub struct FunctionReturn {
-    desc: String,
-    lua_type: String,
+pub struct FunctionReturn<'a> {
+    pub desc: String,
+    pub lua_type: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ast: Option<TypeAst>,
+
+    /// The `@return` tag this return value was documented with, if any
+    #[serde(skip)]
+    pub tag_source: Option<Span<'a>>,
 }
 
-impl<'a> From<ReturnTag<'a>> for FunctionReturn {
-    fn from(tag: ReturnTag) -> Self {
+impl FunctionReturn<'_> {
+    fn new(lua_type: String) -> Self {
+        Self {
+            lua_type,
+            desc: String::new(),
+            type_ast: None,
+            tag_source: None,
+        }
+    }
+}
+
+impl<'a> From<ReturnTag<'a>> for FunctionReturn<'a> {
+    fn from(tag: ReturnTag<'a>) -> Self {
         Self {
             desc: tag.desc.to_string(),
-            lua_type: tag.lua_type.to_string(),
+            tag_source: Some(tag.source),
+            ..Self::new(luau_type(tag.lua_type, &tag.translated_type))
         }
     }
 }
 
+/// One of several ways a function can be called, declared with `@overload` or an intersection
+/// type annotation
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct FunctionSignature<'a> {
+    pub desc: String,
+    pub params: Vec<FunctionParam<'a>>,
+    pub returns: Vec<FunctionReturn<'a>>,
+
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
+}
+
 /// A DocEntry for a function or method.
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Debug, PartialEq, Serialize, Clone)]
 pub struct FunctionDocEntry<'a> {
     pub name: String,
     pub desc: String,
-    pub params: Vec<FunctionParam>,
-    pub returns: Vec<FunctionReturn>,
+    pub params: Vec<FunctionParam<'a>>,
+    pub returns: Vec<FunctionReturn<'a>>,
     pub function_type: FunctionType,
 
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<FunctionSignature<'a>>,
+    /// The type variables declared by the function's Luau annotation, like `T` in `<T>(T) -> T`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<String>,
+
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<CustomTag<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub external_types: Vec<ExternalTag<'a>>,
+    /// Where the references in this entry's descriptions link to, like `Class#method`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ExampleTag<'a>>,
+    /// The ancestor class this member was copied from, set when resolving `@extends`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<ErrorTag<'a>>,
     #[serde(skip_serializing_if = "BTreeSet::is_empty")]
@@ -141,16 +401,32 @@ pub struct FunctionDocEntry<'a> {
     pub unreleased: bool,
     #[serde(skip_serializing_if = "is_false")]
     pub yields: bool,
+    /// Set by `@constructor`, or inferred for a static `new` function that returns its class
+    #[serde(skip_serializing_if = "is_false")]
+    pub constructor: bool,
+    /// Set by `@callback`: this is a signature users implement, rather than a function they call
+    #[serde(skip_serializing_if = "is_false")]
+    pub callback: bool,
     #[serde(skip_serializing_if = "is_false")]
     pub ignore: bool,
 
     #[serde(rename = "source")]
     pub output_source: OutputSource,
 
+    /// The lines of the doc comment that `desc` was joined from
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
     #[serde(skip)]
     pub source: &'a DocComment,
     #[serde(skip)]
     pub within: String,
+    /// Set by `@overload`: this entry is merged into the signatures of the function with the same
+    /// name when classes are assembled
+    #[serde(skip)]
+    pub overload: bool,
+    /// The Luau return type annotation, if `@return` tags replaced it
+    #[serde(skip)]
+    pub annotated_returns: Vec<String>,
 }
 
 impl<'a> FunctionDocEntry<'a> {
@@ -162,6 +438,7 @@ impl<'a> FunctionDocEntry<'a> {
         let DocEntryParseArguments {
             name,
             desc,
+            desc_spans,
             within,
             tags,
             source,
@@ -170,6 +447,10 @@ impl<'a> FunctionDocEntry<'a> {
         let mut doc_entry = Self {
             name,
             desc,
+            desc_spans,
+            links: BTreeMap::new(),
+            examples: Vec::new(),
+            inherited_from: None,
             source,
             function_type,
             since: None,
@@ -177,6 +458,8 @@ impl<'a> FunctionDocEntry<'a> {
             within: within.unwrap(),
             params: Vec::new(),
             returns: Vec::new(),
+            signatures: Vec::new(),
+            generics: Vec::new(),
             tags: Vec::new(),
             external_types: Vec::new(),
             errors: Vec::new(),
@@ -184,11 +467,17 @@ impl<'a> FunctionDocEntry<'a> {
             private: false,
             unreleased: false,
             yields: false,
+            constructor: false,
+            callback: false,
             ignore: false,
+            overload: false,
+            annotated_returns: Vec::new(),
             output_source: source.output_source.clone(),
         };
 
         let mut unused_tags = Vec::new();
+        let mut overloads = Vec::new();
+        let mut constructor_source = None;
 
         let source_exists = if let Some(function_source) = function_source {
             for param in function_source.params {
@@ -199,6 +488,9 @@ impl<'a> FunctionDocEntry<'a> {
                 doc_entry.returns.push(ret)
             }
 
+            overloads = function_source.overloads;
+            doc_entry.generics = function_source.generics;
+
             true
         } else {
             false
@@ -213,11 +505,22 @@ impl<'a> FunctionDocEntry<'a> {
                             param.name.as_str().replace('?', "") == existing_param.name
                         }) {
                             found.desc = param.desc.to_string();
+                            found.tag_source = Some(param.source);
 
                             if !param.lua_type.is_empty() {
-                                found.lua_type = param.lua_type.to_string();
+                                if !found.lua_type.is_empty() {
+                                    found.annotated_type = Some(found.lua_type.clone());
+                                }
+
+                                found.lua_type = luau_type(param.lua_type, &param.translated_type);
                             }
 
+                            if let Some(default) = param.default {
+                                found.default = Some(default.to_string());
+                            }
+
+                            found.optional |= param.optional;
+
                             // Special case for params ending with ?
                             // Luau doesn't actually allow this syntax but users use it
                             if param.name.ends_with('?') && !found.name.ends_with('?') {
@@ -238,7 +541,11 @@ impl<'a> FunctionDocEntry<'a> {
                 }
                 Tag::Return(return_tag) => {
                     if source_exists && !return_cleared {
-                        doc_entry.returns.clear();
+                        doc_entry.annotated_returns = doc_entry
+                            .returns
+                            .drain(..)
+                            .map(|source_return| source_return.lua_type)
+                            .collect();
                         return_cleared = true;
                     }
 
@@ -248,12 +555,19 @@ impl<'a> FunctionDocEntry<'a> {
                 Tag::Since(since_tag) => doc_entry.since = Some(since_tag.version.to_string()),
                 Tag::Custom(custom_tag) => doc_entry.tags.push(custom_tag),
                 Tag::External(external_tag) => doc_entry.external_types.push(external_tag),
+                Tag::Example(example_tag) => doc_entry.examples.push(example_tag),
                 Tag::Error(error_tag) => doc_entry.errors.push(error_tag),
 
                 Tag::Private(_) => doc_entry.private = true,
                 Tag::Unreleased(_) => doc_entry.unreleased = true,
                 Tag::Yields(_) => doc_entry.yields = true,
                 Tag::Ignore(_) => doc_entry.ignore = true,
+                Tag::Overload(_) => doc_entry.overload = true,
+                Tag::Constructor(constructor_tag) => {
+                    doc_entry.constructor = true;
+                    constructor_source = Some(constructor_tag.source);
+                }
+                Tag::Callback(_) => doc_entry.callback = true,
 
                 Tag::Server(_) => {
                     doc_entry.realm.insert(Realm::Server);
@@ -268,8 +582,41 @@ impl<'a> FunctionDocEntry<'a> {
             }
         }
 
+        doc_entry
+            .params
+            .iter_mut()
+            .for_each(FunctionParam::infer_flags);
+
+        if !overloads.is_empty() {
+            doc_entry.signatures.push(doc_entry.signature());
+            doc_entry
+                .signatures
+                .extend(overloads.into_iter().map(|mut overload| {
+                    overload
+                        .params
+                        .iter_mut()
+                        .for_each(FunctionParam::infer_flags);
+
+                    FunctionSignature {
+                        desc: String::new(),
+                        params: overload.params,
+                        returns: overload.returns,
+                        desc_spans: Vec::new(),
+                    }
+                }));
+        }
+
+        if doc_entry.is_inferred_constructor() {
+            doc_entry.constructor = true;
+        }
+
         let mut diagnostics = Vec::new();
-        for param in doc_entry.params.iter() {
+
+        if let (Some(span), FunctionType::Method) = (constructor_source, &doc_entry.function_type) {
+            diagnostics.push(span.diagnostic("Constructors must be static functions, not methods"));
+        }
+
+        for param in doc_entry.all_params() {
             if param.lua_type.is_empty() {
                 diagnostics.push(Diagnostic::from_doc_comment(
                     format!("Function parameter \"{}\" has no type. Document with @param or insert Luau type annotation", param.name),
@@ -294,3 +641,84 @@ impl<'a> FunctionDocEntry<'a> {
         Ok(doc_entry)
     }
 }
+
+impl<'a> FunctionDocEntry<'a> {
+    /// Whether this is a static `new` function that returns an instance of the class it's within
+    fn is_inferred_constructor(&self) -> bool {
+        let is_class = |lua_type: &str| {
+            lua_type == self.within || Some(lua_type) == self.within.rsplit('.').next()
+        };
+
+        self.name == "new"
+            && self.function_type == FunctionType::Static
+            && self.returns.len() == 1
+            && is_class(self.returns[0].lua_type.trim())
+    }
+
+    /// The signature described by the flat `params` and `returns` of this entry
+    pub fn signature(&self) -> FunctionSignature<'a> {
+        FunctionSignature {
+            desc: self.desc.clone(),
+            params: self.params.clone(),
+            returns: self.returns.clone(),
+            desc_spans: self.desc_spans.clone(),
+        }
+    }
+
+    /// Merges an `@overload` entry into this function's list of signatures
+    pub fn add_overload(&mut self, overload: FunctionDocEntry<'a>) {
+        if self.signatures.is_empty() {
+            self.signatures.push(self.signature());
+        }
+
+        if overload.signatures.is_empty() {
+            self.signatures.push(overload.signature());
+        } else {
+            self.signatures.extend(overload.signatures);
+        }
+
+        self.examples.extend(overload.examples);
+    }
+
+    /// Every parameter of every signature of this function. The first signature is a copy of
+    /// `params`, so it's skipped.
+    pub fn all_params_mut(&mut self) -> impl Iterator<Item = &mut FunctionParam<'a>> {
+        self.params.iter_mut().chain(
+            self.signatures
+                .iter_mut()
+                .skip(1)
+                .flat_map(|signature| signature.params.iter_mut()),
+        )
+    }
+
+    /// Every return value of every signature of this function. The first signature is a copy of
+    /// `returns`, so it's skipped.
+    pub fn all_returns_mut(&mut self) -> impl Iterator<Item = &mut FunctionReturn<'a>> {
+        self.returns.iter_mut().chain(
+            self.signatures
+                .iter_mut()
+                .skip(1)
+                .flat_map(|signature| signature.returns.iter_mut()),
+        )
+    }
+
+    /// Every parameter of every signature of this function
+    pub fn all_params(&self) -> impl Iterator<Item = &FunctionParam<'a>> {
+        self.params.iter().chain(
+            self.signatures
+                .iter()
+                .skip(1)
+                .flat_map(|signature| signature.params.iter()),
+        )
+    }
+
+    /// Every return value of every signature of this function
+    pub fn all_returns(&self) -> impl Iterator<Item = &FunctionReturn<'a>> {
+        self.returns.iter().chain(
+            self.signatures
+                .iter()
+                .skip(1)
+                .flat_map(|signature| signature.returns.iter()),
+        )
+    }
+}
@@ -1,27 +1,39 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::{
     diagnostic::Diagnostics,
     doc_comment::{DocComment, OutputSource},
     realm::Realm,
     serde_util::is_false,
-    tags::{CustomTag, DeprecatedTag, ExternalTag, Tag},
+    span::Span,
+    tags::{luau_type, CustomTag, DeprecatedTag, ExampleTag, ExternalTag, Tag},
+    type_ast::TypeAst,
 };
 use serde::Serialize;
 
 use super::DocEntryParseArguments;
 
 /// A DocEntry for a function or method.
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Debug, PartialEq, Serialize, Clone)]
 pub struct PropertyDocEntry<'a> {
     pub name: String,
     pub desc: String,
     pub lua_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ast: Option<TypeAst>,
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<CustomTag<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub external_types: Vec<ExternalTag<'a>>,
+    /// Where the references in this entry's descriptions link to, like `Class#method`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ExampleTag<'a>>,
+    /// The ancestor class this member was copied from, set when resolving `@extends`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_from: Option<String>,
     #[serde(skip_serializing_if = "BTreeSet::is_empty")]
     pub realm: BTreeSet<Realm>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -40,10 +52,16 @@ pub struct PropertyDocEntry<'a> {
     #[serde(rename = "source")]
     pub output_source: OutputSource,
 
+    /// The lines of the doc comment that `desc` was joined from
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
     #[serde(skip)]
     pub source: &'a DocComment,
     #[serde(skip)]
     pub within: String,
+    /// The `@prop` tag this property was documented with
+    #[serde(skip)]
+    pub tag_source: Option<Span<'a>>,
 }
 
 impl<'a> PropertyDocEntry<'a> {
@@ -51,6 +69,7 @@ impl<'a> PropertyDocEntry<'a> {
         let DocEntryParseArguments {
             name,
             desc,
+            desc_spans,
             within,
             tags,
             source,
@@ -59,8 +78,14 @@ impl<'a> PropertyDocEntry<'a> {
         let mut doc_entry = Self {
             name,
             desc,
+            desc_spans,
+            links: BTreeMap::new(),
+            examples: Vec::new(),
+            inherited_from: None,
             source,
             lua_type: String::new(),
+            type_ast: None,
+            tag_source: None,
             since: None,
             deprecated: None,
             within: within.unwrap(),
@@ -79,13 +104,16 @@ impl<'a> PropertyDocEntry<'a> {
         for tag in tags {
             match tag {
                 Tag::Property(property_tag) => {
-                    doc_entry.lua_type = property_tag.lua_type.as_str().to_owned()
+                    doc_entry.lua_type =
+                        luau_type(property_tag.lua_type, &property_tag.translated_type);
+                    doc_entry.tag_source = Some(property_tag.source);
                 }
 
                 Tag::Deprecated(deprecated_tag) => doc_entry.deprecated = Some(deprecated_tag),
                 Tag::Since(since_tag) => doc_entry.since = Some(since_tag.version.to_string()),
                 Tag::Custom(custom_tag) => doc_entry.tags.push(custom_tag),
                 Tag::External(external_tag) => doc_entry.external_types.push(external_tag),
+                Tag::Example(example_tag) => doc_entry.examples.push(example_tag),
 
                 Tag::Private(_) => doc_entry.private = true,
                 Tag::Unreleased(_) => doc_entry.unreleased = true,
@@ -1,30 +1,62 @@
+use std::collections::BTreeMap;
+
 use crate::{
     diagnostic::Diagnostics,
     doc_comment::{DocComment, OutputSource},
     serde_util::is_false,
-    tags::{CustomTag, DeprecatedTag, ExternalTag, FieldTag, Tag},
+    span::Span,
+    tags::{luau_type, CustomTag, DeprecatedTag, ExampleTag, ExternalTag, FieldTag, Tag},
+    type_ast::TypeAst,
 };
 use serde::Serialize;
 
 use super::DocEntryParseArguments;
 
 #[derive(Debug, PartialEq, Serialize)]
-pub struct Field {
+pub struct Field<'a> {
     pub name: String,
     pub lua_type: String,
     pub desc: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ast: Option<TypeAst>,
+
+    #[serde(skip)]
+    pub tag_source: Span<'a>,
 }
 
-impl<'a> From<FieldTag<'a>> for Field {
+impl<'a> From<FieldTag<'a>> for Field<'a> {
     fn from(field_tag: FieldTag<'a>) -> Self {
         Self {
             name: field_tag.name.as_str().to_owned(),
-            lua_type: field_tag.lua_type.as_str().to_owned(),
+            lua_type: luau_type(field_tag.lua_type, &field_tag.translated_type),
             desc: field_tag.desc.as_str().to_owned(),
+            type_ast: None,
+            tag_source: field_tag.source,
         }
     }
 }
 
+/// Splits the generics a type declares off of its name, so `Map<Key, Value = any>` becomes `Map`
+/// with the generics `Key` and `Value`
+fn split_generics(name: String) -> (String, Vec<String>) {
+    let Some((base, generics)) = name.split_once('<') else {
+        return (name, Vec::new());
+    };
+
+    let generics = generics
+        .trim_end_matches('>')
+        .split(',')
+        .map(|generic| {
+            let generic = generic.split('=').next().unwrap_or(generic).trim();
+            generic.trim_end_matches("...").to_owned()
+        })
+        .filter(|generic| !generic.is_empty())
+        .collect();
+
+    (base.trim().to_owned(), generics)
+}
+
 /// A DocEntry for a function or method.
 #[derive(Debug, PartialEq, Serialize)]
 pub struct TypeDocEntry<'a> {
@@ -33,13 +65,23 @@ pub struct TypeDocEntry<'a> {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub lua_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_ast: Option<TypeAst>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field<'a>>,
+    /// The type variables the type declares, like `Key` and `Value` in `Map<Key, Value>`
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub fields: Vec<Field>,
+    pub generics: Vec<String>,
 
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<CustomTag<'a>>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub external_types: Vec<ExternalTag<'a>>,
+    /// Where the references in this entry's descriptions link to, like `Class#method`
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub links: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<ExampleTag<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub since: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -54,10 +96,16 @@ pub struct TypeDocEntry<'a> {
     #[serde(rename = "source")]
     pub output_source: OutputSource,
 
+    /// The lines of the doc comment that `desc` was joined from
+    #[serde(skip)]
+    pub desc_spans: Vec<Span<'a>>,
     #[serde(skip)]
     pub source: &'a DocComment,
     #[serde(skip)]
     pub within: String,
+    /// The `@type` tag this type was documented with, if any
+    #[serde(skip)]
+    pub tag_source: Option<Span<'a>>,
 }
 
 impl<'a> TypeDocEntry<'a> {
@@ -65,16 +113,25 @@ impl<'a> TypeDocEntry<'a> {
         let DocEntryParseArguments {
             name,
             desc,
+            desc_spans,
             within,
             tags,
             source,
         } = args;
 
+        let (name, generics) = split_generics(name);
+
         let mut doc_entry = Self {
             name,
+            generics,
             desc,
+            desc_spans,
+            links: BTreeMap::new(),
+            examples: Vec::new(),
             source,
             lua_type: None,
+            type_ast: None,
+            tag_source: None,
             since: None,
             deprecated: None,
             fields: Vec::new(),
@@ -92,7 +149,8 @@ impl<'a> TypeDocEntry<'a> {
         for tag in tags {
             match tag {
                 Tag::Type(type_tag) => {
-                    doc_entry.lua_type = Some(type_tag.lua_type.as_str().to_owned())
+                    doc_entry.lua_type = Some(type_tag.lua_type.as_str().to_owned());
+                    doc_entry.tag_source = Some(type_tag.source);
                 }
 
                 Tag::Field(field_tag) => doc_entry.fields.push(field_tag.into()),
@@ -101,6 +159,7 @@ impl<'a> TypeDocEntry<'a> {
                 Tag::Since(since_tag) => doc_entry.since = Some(since_tag.version.to_string()),
                 Tag::Custom(custom_tag) => doc_entry.tags.push(custom_tag),
                 Tag::External(external_tag) => doc_entry.external_types.push(external_tag),
+                Tag::Example(example_tag) => doc_entry.examples.push(example_tag),
 
                 Tag::Private(_) => doc_entry.private = true,
                 Tag::Unreleased(_) => doc_entry.unreleased = true,
@@ -1,6 +1,6 @@
 use std::{
-    collections::{BTreeMap, HashMap},
-    io,
+    collections::{BTreeMap, HashMap, HashSet},
+    io::{self, BufWriter, Read, Write},
     path::{self, Path, PathBuf},
 };
 
@@ -18,27 +18,43 @@ use codespan_reporting::{
 
 use diagnostic::{Diagnostic, Diagnostics};
 use doc_comment::DocComment;
-use doc_entry::{ClassDocEntry, DocEntry, FunctionDocEntry, PropertyDocEntry, TypeDocEntry};
+use doc_entry::{
+    ClassDocEntry, DocEntry, EventDocEntry, FunctionDocEntry, PropertyDocEntry, TypeDocEntry,
+};
 use pathdiff::diff_paths;
 use serde::Serialize;
 
+use ignore::{
+    overrides::{Override, OverrideBuilder},
+    WalkBuilder,
+};
 use tags::{validate_global_tags, Tag};
-use walkdir::{self, WalkDir};
 
 mod cli;
+pub mod compat;
 mod diagnostic;
+pub mod dialect;
 mod doc_comment;
 mod doc_entry;
 pub mod error;
+mod luals;
+mod passes;
 pub mod realm;
+mod rojo;
+mod selene;
 mod serde_util;
 pub mod source_file;
 mod span;
 mod tags;
+mod type_ast;
+
+#[cfg(not(feature = "roblox"))]
+compile_error!("the \"roblox\" feature is required, since doc comment types are parsed as Luau");
 
 pub use cli::*;
 
 use error::Error;
+use rojo::RojoProject;
 use source_file::SourceFile;
 
 /// The class struct that is used in the main output, which owns its members
@@ -47,15 +63,158 @@ struct OutputClass<'a> {
     functions: Vec<FunctionDocEntry<'a>>,
     properties: Vec<PropertyDocEntry<'a>>,
     types: Vec<TypeDocEntry<'a>>,
+    events: Vec<EventDocEntry<'a>>,
+
+    /// The classes this class inherits from through `@extends`, from nearest to furthest
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    ancestors: Vec<String>,
+
+    /// The nearest documented class whose name is a prefix of this one, like `Net.Server` for
+    /// `Net.Server.Remote`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<String>,
+    /// The classes whose `parent` is this class
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    children: Vec<String>,
 
     #[serde(flatten)]
     class: ClassDocEntry<'a>,
 }
 
+/// Everything the extractor outputs
+#[derive(Debug, Serialize)]
+struct Output<'a> {
+    classes: Vec<OutputClass<'a>>,
+    /// Entries documented with `@module`, which have the same shape as classes
+    modules: Vec<OutputClass<'a>>,
+}
+
 type CodespanFilesPaths = (PathBuf, usize);
 
-pub fn generate_docs_from_path(input_path: &Path, base_path: &Path) -> anyhow::Result<()> {
-    let (codespan_files, files) = find_files(input_path)?;
+pub fn generate_docs_from_path(
+    input_paths: &[PathBuf],
+    base_path: &Path,
+    output_options: &OutputOptions,
+    options: &ExtractOptions,
+) -> anyhow::Result<()> {
+    extract(input_paths, Some(base_path), options, |classes| {
+        let (modules, classes) = classes.into_iter().partition(|class| class.class.module);
+        let output = Output { classes, modules };
+
+        match &output_options.output {
+            Some(path) => write_atomically(path, |writer| {
+                write_json(writer, &output, output_options.compact)
+            }),
+            None => write_json(&mut io::stdout().lock(), &output, output_options.compact),
+        }
+    })
+}
+
+/// Serializes the output straight to a writer, rather than building a string first
+fn write_json(writer: &mut impl Write, output: &Output, compact: bool) -> anyhow::Result<()> {
+    let mut writer = BufWriter::new(writer);
+
+    match compact {
+        true => serde_json::to_writer(&mut writer, output)?,
+        false => serde_json::to_writer_pretty(&mut writer, output)?,
+    }
+
+    writeln!(writer)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Writes to a temporary file next to the path, then renames it over the path, so that a
+/// half-written file is never read
+fn write_atomically(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("{} is not a file path", path.display()))?;
+
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = (|| -> anyhow::Result<()> {
+        let mut file = fs::File::create(&temp_path)?;
+        write(&mut file)?;
+        file.sync_all()?;
+        fs::rename(&temp_path, path)?;
+
+        Ok(())
+    })();
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+/// Writes a lua-language-server stub file for each class into `out_dir`, instead of printing
+/// the docs
+pub fn generate_luals_stubs(
+    input_paths: &[PathBuf],
+    out_dir: &Path,
+    options: &ExtractOptions,
+) -> anyhow::Result<()> {
+    extract(input_paths, None, options, |classes| {
+        luals::write_stubs(&classes, out_dir)
+    })
+}
+
+/// Prints a selene standard library definition for the classes, instead of the docs
+pub fn generate_selene_std(
+    input_paths: &[PathBuf],
+    name: &str,
+    options: &ExtractOptions,
+) -> anyhow::Result<()> {
+    extract(input_paths, None, options, |classes| {
+        print!("{}", selene::std_file(&classes, name));
+
+        Ok(())
+    })
+}
+
+/// Extracts the classes from every file in the input paths and runs the passes over them, so that
+/// entries in one path can be within classes in another. The classes are handed to `emit` if
+/// there were no errors, and any diagnostics are reported. Source paths are relative to the base
+/// path, if there is one.
+fn extract(
+    input_paths: &[PathBuf],
+    base_path: Option<&Path>,
+    options: &ExtractOptions,
+    emit: impl for<'a> FnOnce(Vec<OutputClass<'a>>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let rojo_project = match &options.rojo_project {
+        Some(path) => Some(RojoProject::load(path)?),
+        None => None,
+    };
+
+    let mut known_types = options.known_types.clone();
+
+    if let Some(path) = &options.known_types_file {
+        let list = fs::read_to_string(path)?;
+
+        known_types.extend(
+            list.lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_owned),
+        );
+    }
+
+    let (codespan_files, files) = find_files(input_paths, options)?;
+    let file_paths: HashMap<usize, PathBuf> = files
+        .iter()
+        .map(|(file_path, file_id)| (*file_id, file_path.clone()))
+        .collect();
 
     let mut errors: Vec<Error> = Vec::new();
     let mut source_files: Vec<SourceFile> = Vec::new();
@@ -63,7 +222,7 @@ pub fn generate_docs_from_path(input_path: &Path, base_path: &Path) -> anyhow::R
     for (file_path, file_id) in files {
         let source = codespan_files.get(file_id).unwrap().source();
 
-        let human_path = match diff_paths(&file_path, base_path) {
+        let human_path = match base_path.and_then(|base_path| diff_paths(&file_path, base_path)) {
             Some(relative_path) => relative_path,
             None => file_path,
         };
@@ -73,7 +232,13 @@ pub fn generate_docs_from_path(input_path: &Path, base_path: &Path) -> anyhow::R
             .to_string()
             .replace(path::MAIN_SEPARATOR, "/");
 
-        match SourceFile::from_str(source, file_id, human_path) {
+        match SourceFile::from_str(
+            source,
+            file_id,
+            human_path,
+            options.dialect,
+            &options.compat,
+        ) {
             Ok(source_file) => source_files.push(source_file),
             Err(error) => errors.push(error),
         }
@@ -81,7 +246,7 @@ pub fn generate_docs_from_path(input_path: &Path, base_path: &Path) -> anyhow::R
 
     let (results, source_file_errors): (Vec<_>, Vec<_>) = source_files
         .iter()
-        .map(SourceFile::parse)
+        .map(|source_file| source_file.parse(&options.compat))
         .partition(Result::is_ok);
 
     errors.extend(source_file_errors.into_iter().map(Result::unwrap_err));
@@ -95,19 +260,67 @@ pub fn generate_docs_from_path(input_path: &Path, base_path: &Path) -> anyhow::R
         errors.push(Error::ParseErrors(Diagnostics::from(diagnostics)));
     }
 
+    let mut warnings: Vec<Diagnostic> = Vec::new();
+
     match into_classes(entries) {
-        Ok(classes) => {
+        Ok(mut classes) => {
+            let mut diagnostics = Vec::new();
+
+            diagnostics.extend(passes::parse_types(&mut classes));
+            diagnostics.extend(passes::check_annotations(&classes));
+            diagnostics.extend(passes::check_code_blocks(&classes, options.dialect));
+            diagnostics.extend(passes::resolve_links(
+                &mut classes,
+                &known_types,
+                options.check_types,
+            ));
+
+            if let Some(project) = &rojo_project {
+                diagnostics.extend(passes::map_data_model_paths(
+                    &mut classes,
+                    project,
+                    &file_paths,
+                ));
+            }
+
+            if options.check_types {
+                diagnostics.extend(passes::resolve_types(&classes, &known_types));
+            }
+
+            // Runs after the other checks so that members are checked once, before they're copied
+            // into subclasses
+            diagnostics.extend(passes::resolve_inheritance(&mut classes));
+
+            // Inherited members count towards implementing an interface
+            diagnostics.extend(passes::check_implements(&classes));
+
+            let (pass_errors, pass_warnings): (Vec<_>, Vec<_>) =
+                diagnostics.into_iter().partition(Diagnostic::is_error);
+
+            warnings.extend(pass_warnings);
+
+            if !pass_errors.is_empty() {
+                errors.push(Error::ParseErrors(Diagnostics::from(pass_errors)));
+            }
+
             if errors.is_empty() {
-                println!("{}", serde_json::to_string_pretty(&classes)?);
+                emit(classes)?;
             }
         }
         Err(diagnostics) => errors.push(Error::ParseErrors(diagnostics)),
     }
 
+    if !warnings.is_empty() {
+        report_diagnostics(
+            vec![Error::ParseErrors(Diagnostics::from(warnings))],
+            &codespan_files,
+        );
+    }
+
     if !errors.is_empty() {
         let count_errors = errors.len();
 
-        report_errors(errors, &codespan_files);
+        report_diagnostics(errors, &codespan_files);
 
         if count_errors == 1 {
             bail!("aborting due to diagnostic error");
@@ -130,7 +343,8 @@ fn into_classes<'a>(entries: Vec<DocEntry<'a>>) -> Result<Vec<OutputClass<'a>>,
 
     for entry in classes {
         if let DocEntry::Class(class) = entry {
-            let (functions, properties, types) = Default::default();
+            let (functions, properties, types, events) = Default::default();
+            let (ancestors, parent, children) = Default::default();
 
             let class_name = class.name.to_owned();
             let __index = class.__index.to_owned();
@@ -142,6 +356,10 @@ fn into_classes<'a>(entries: Vec<DocEntry<'a>>) -> Result<Vec<OutputClass<'a>>,
                     functions,
                     properties,
                     types,
+                    events,
+                    ancestors,
+                    parent,
+                    children,
                 },
             );
 
@@ -164,63 +382,237 @@ fn into_classes<'a>(entries: Vec<DocEntry<'a>>) -> Result<Vec<OutputClass<'a>>,
 
     for entry in entries {
         match entry {
-            DocEntry::Function(entry) => match alias_map.get(&entry.within) {
-                Some(class_name) => map.get_mut(class_name).unwrap().functions.push(entry),
-                None => emit_diagnostic(entry.source, &entry.within),
-            },
-            DocEntry::Property(entry) => match alias_map.get(&entry.within) {
-                Some(class_name) => map.get_mut(class_name).unwrap().properties.push(entry),
-                None => emit_diagnostic(entry.source, &entry.within),
-            },
-            DocEntry::Type(entry) => match alias_map.get(&entry.within) {
-                Some(class_name) => map.get_mut(class_name).unwrap().types.push(entry),
-                None => emit_diagnostic(entry.source, &entry.within),
-            },
-            _ => unreachable!(),
+            DocEntry::Function(mut entry) => {
+                match resolve_within(&alias_map, &entry.within, &mut entry.name) {
+                    Some(class_name) => map.get_mut(class_name).unwrap().functions.push(entry),
+                    None => emit_diagnostic(entry.source, &entry.within),
+                }
+            }
+            DocEntry::Property(mut entry) => {
+                match resolve_within(&alias_map, &entry.within, &mut entry.name) {
+                    Some(class_name) => map.get_mut(class_name).unwrap().properties.push(entry),
+                    None => emit_diagnostic(entry.source, &entry.within),
+                }
+            }
+            DocEntry::Type(mut entry) => {
+                match resolve_within(&alias_map, &entry.within, &mut entry.name) {
+                    Some(class_name) => map.get_mut(class_name).unwrap().types.push(entry),
+                    None => emit_diagnostic(entry.source, &entry.within),
+                }
+            }
+            DocEntry::Event(mut entry) => {
+                match resolve_within(&alias_map, &entry.within, &mut entry.name) {
+                    Some(class_name) => map.get_mut(class_name).unwrap().events.push(entry),
+                    None => emit_diagnostic(entry.source, &entry.within),
+                }
+            }
+            DocEntry::Class(_) => unreachable!(),
         };
     }
 
+    for class in map.values_mut() {
+        merge_overloads(&mut class.functions);
+    }
+
+    link_namespaces(&mut map);
+
     if diagnostics.is_empty() {
-        Ok(map.into_iter().map(|(_, value)| value).collect())
+        Ok(map.into_values().collect())
     } else {
         Err(Diagnostics::from(diagnostics))
     }
 }
 
-fn find_files(
+/// Finds the class an entry is within. Qualified names are split at their last `.`, so if that
+/// isn't a class, shorter prefixes are tried and the rest is moved into the entry's name. That way
+/// `QualifiedNames.lots.of.dots` documents `lots.of.dots` within `QualifiedNames`.
+fn resolve_within<'m>(
+    alias_map: &'m HashMap<String, String>,
+    within: &str,
+    name: &mut String,
+) -> Option<&'m String> {
+    let mut prefix = within;
+
+    loop {
+        if let Some(class_name) = alias_map.get(prefix) {
+            if prefix.len() < within.len() {
+                *name = format!("{}.{}", &within[prefix.len() + 1..], name);
+            }
+
+            return Some(class_name);
+        }
+
+        prefix = prefix.rsplit_once('.')?.0;
+    }
+}
+
+/// Links dotted class names like `Net.Server.Remote` into a tree. Each class's parent is the
+/// longest prefix of its name that is also a documented class, so undocumented namespaces in
+/// between are skipped over.
+fn link_namespaces(map: &mut BTreeMap<String, OutputClass>) {
+    let names: Vec<String> = map.keys().cloned().collect();
+
+    for name in names {
+        let mut prefix = name.as_str();
+
+        while let Some((parent, _)) = prefix.rsplit_once('.') {
+            if let Some(parent_class) = map.get_mut(parent) {
+                parent_class.children.push(name.clone());
+                map.get_mut(&name).unwrap().parent = Some(parent.to_owned());
+
+                break;
+            }
+
+            prefix = parent;
+        }
+    }
+}
+
+/// Folds `@overload` entries into the signatures of the function they share a name with. If no
+/// function without `@overload` exists, the first overload is used in its place.
+fn merge_overloads(functions: &mut Vec<FunctionDocEntry>) {
+    let (overloads, mut merged): (Vec<_>, Vec<_>) =
+        functions.drain(..).partition(|function| function.overload);
+
+    for overload in overloads {
+        match merged
+            .iter_mut()
+            .find(|function| function.name == overload.name)
+        {
+            Some(function) => function.add_overload(overload),
+            None => merged.push(overload),
+        }
+    }
+
+    *functions = merged;
+}
+
+fn is_source_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|s| s.to_str()),
+        Some("lua") | Some("luau")
+    )
+}
+
+/// Walks the source files under a path, optionally with the filters from ignore files and
+/// `--include` and `--exclude` globs. Files are sorted so they're always read in the same order.
+fn walk_source_files(
     path: &Path,
-) -> Result<(SimpleFiles<String, String>, Vec<CodespanFilesPaths>), io::Error> {
+    filters: Option<Override>,
+) -> impl Iterator<Item = ignore::DirEntry> {
+    let mut walker = WalkBuilder::new(path);
+    walker.follow_links(true).sort_by_file_name(|a, b| a.cmp(b));
+
+    match filters {
+        Some(overrides) => walker.hidden(false).require_git(false).overrides(overrides),
+        None => walker.standard_filters(false),
+    };
+
+    walker
+        .build()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_some_and(|file_type| file_type.is_file()))
+        .filter(|e| is_source_file(e.path()))
+}
+
+fn add_file(
+    codespan_files: &mut SimpleFiles<String, String>,
+    files: &mut Vec<CodespanFilesPaths>,
+    path: &Path,
+    contents: String,
+) {
+    let file_id = codespan_files.add(
+        // We need the separator to consistently be forward slashes for snapshot
+        // consistency across platforms
+        path.to_string_lossy().replace(path::MAIN_SEPARATOR, "/"),
+        contents,
+    );
+
+    files.push((path.to_path_buf(), file_id));
+}
+
+/// Finds the source files in every input path, along with the file read from stdin and the files
+/// listed by `--files-from`. Globs are matched against paths within each input path, and files
+/// found more than once are only read once.
+fn find_files(
+    input_paths: &[PathBuf],
+    options: &ExtractOptions,
+) -> anyhow::Result<(SimpleFiles<String, String>, Vec<CodespanFilesPaths>)> {
     let mut codespan_files = SimpleFiles::new();
     let mut files: Vec<CodespanFilesPaths> = Vec::new();
+    // Files are keyed by their canonical path, so `./src` and `src` aren't read twice. The path
+    // they were found by is kept for display.
+    let mut seen: HashSet<PathBuf> = HashSet::new();
 
-    let walker = WalkDir::new(path).follow_links(true).into_iter();
-    for entry in walker
-        .filter_map(|e| e.ok())
-        .filter(|e| e.file_type().is_file())
-        .filter(|e| {
-            matches!(
-                e.path().extension().and_then(|s| s.to_str()),
-                Some("lua") | Some("luau")
-            )
-        })
-    {
-        let path = entry.path();
-        let contents = fs::read_to_string(path)?;
-
-        let file_id = codespan_files.add(
-            // We need the separator to consistently be forward slashes for snapshot
-            // consistency across platforms
-            path.to_string_lossy().replace(path::MAIN_SEPARATOR, "/"),
-            contents,
-        );
+    if let Some(stdin_filename) = &options.stdin_filename {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
 
-        files.push((path.to_path_buf(), file_id));
+        seen.insert(canonical_path(stdin_filename));
+        add_file(&mut codespan_files, &mut files, stdin_filename, contents);
+    }
+
+    if let Some(files_from) = &options.files_from {
+        let list = fs::read_to_string(files_from)?;
+
+        for line in list.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let path = PathBuf::from(line);
+
+            if seen.insert(canonical_path(&path)) {
+                let contents = fs::read_to_string(&path)?;
+                add_file(&mut codespan_files, &mut files, &path, contents);
+            }
+        }
+    }
+
+    for input_path in input_paths {
+        let mut overrides = OverrideBuilder::new(input_path);
+        for glob in &options.include {
+            overrides.add(glob)?;
+        }
+        for glob in &options.exclude {
+            overrides.add(&format!("!{}", glob))?;
+        }
+
+        for entry in walk_source_files(input_path, Some(overrides.build()?)) {
+            let path = entry.path();
+
+            if seen.insert(canonical_path(path)) {
+                let contents = fs::read_to_string(path)?;
+                add_file(&mut codespan_files, &mut files, path, contents);
+            }
+        }
+
+        if options.verbose {
+            report_skipped_files(input_path, &seen);
+        }
     }
 
     Ok((codespan_files, files))
 }
 
-fn report_errors(errors: Vec<Error>, codespan_files: &SimpleFiles<String, String>) {
+/// The canonical form of a path, or the path itself if it doesn't exist, like the name given to
+/// a file read from stdin
+fn canonical_path(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Lists the source files under a path that were skipped because of an ignore file or a glob
+fn report_skipped_files(path: &Path, found: &HashSet<PathBuf>) {
+    for entry in walk_source_files(path, None) {
+        if !found.contains(&canonical_path(entry.path())) {
+            eprintln!(
+                "Skipped {}",
+                entry
+                    .path()
+                    .to_string_lossy()
+                    .replace(path::MAIN_SEPARATOR, "/")
+            );
+        }
+    }
+}
+
+fn report_diagnostics(errors: Vec<Error>, codespan_files: &SimpleFiles<String, String>) {
     let writer = StandardStream::stderr(ColorChoice::Auto);
     let config = codespan_reporting::term::Config {
         end_context_lines: usize::MAX,
@@ -0,0 +1,480 @@
+use std::{collections::BTreeSet, path::Path};
+
+use fs_err as fs;
+
+use crate::{
+    doc_entry::{
+        FunctionDocEntry, FunctionParam, FunctionReturn, FunctionType, PropertyDocEntry,
+        TypeDocEntry,
+    },
+    type_ast::{TypeAst, TypeAstField, TypeAstParam},
+    OutputClass,
+};
+
+/// Writes a lua-language-server `---@meta` stub file for each class, named after the class
+pub fn write_stubs(classes: &[OutputClass], out_dir: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(out_dir)?;
+
+    for class in classes.iter().filter(|class| !class.class.ignore) {
+        let path = out_dir.join(format!("{}.lua", class.class.name));
+
+        fs::write(path, class_stub(class))?;
+    }
+
+    Ok(())
+}
+
+/// Class names can have dots in them, which can't be used in the name of a local
+fn local_name(class_name: &str) -> String {
+    class_name
+        .chars()
+        .map(|char| match char {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' => char,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// The types and callbacks a class declares. lua-language-server's types are global, so they're
+/// named `Class.Type` in stubs, which is also how other classes refer to them.
+struct ClassTypes<'c> {
+    class_name: &'c str,
+    names: BTreeSet<&'c str>,
+}
+
+impl<'c> ClassTypes<'c> {
+    fn new(class: &'c OutputClass) -> Self {
+        let types = class
+            .types
+            .iter()
+            .map(|type_entry| type_entry.name.as_str());
+        let callbacks = class
+            .functions
+            .iter()
+            .filter(|function| function.callback)
+            .map(|function| function.name.as_str());
+
+        Self {
+            class_name: &class.class.name,
+            names: types.chain(callbacks).collect(),
+        }
+    }
+
+    fn qualified_name(&self, name: &str) -> String {
+        format!("{}.{}", self.class_name, name)
+    }
+
+    /// Rewrites the names in a type that refer to this class's types as `Class.Type`
+    fn qualify(&self, type_ast: &TypeAst) -> TypeAst {
+        let qualify_all =
+            |types: &[TypeAst]| types.iter().map(|inner| self.qualify(inner)).collect();
+        let qualify_name = |name: &String| match self.names.contains(name.as_str()) {
+            true => self.qualified_name(name),
+            false => name.clone(),
+        };
+
+        match type_ast {
+            TypeAst::Reference { name } => TypeAst::Reference {
+                name: qualify_name(name),
+            },
+            TypeAst::Generic { name, generics } => TypeAst::Generic {
+                name: qualify_name(name),
+                generics: qualify_all(generics),
+            },
+            TypeAst::Optional { inner } => TypeAst::Optional {
+                inner: Box::new(self.qualify(inner)),
+            },
+            TypeAst::Union { types } => TypeAst::Union {
+                types: qualify_all(types),
+            },
+            TypeAst::Intersection { types } => TypeAst::Intersection {
+                types: qualify_all(types),
+            },
+            TypeAst::Function { params, returns } => TypeAst::Function {
+                params: params
+                    .iter()
+                    .map(|param| TypeAstParam {
+                        name: param.name.clone(),
+                        lua_type: self.qualify(&param.lua_type),
+                    })
+                    .collect(),
+                returns: qualify_all(returns),
+            },
+            TypeAst::Table { fields } => TypeAst::Table {
+                fields: fields
+                    .iter()
+                    .map(|field| TypeAstField {
+                        name: field.name.clone(),
+                        indexer: field.indexer.as_ref().map(|indexer| self.qualify(indexer)),
+                        value: self.qualify(&field.value),
+                    })
+                    .collect(),
+            },
+            TypeAst::Array { element } => TypeAst::Array {
+                element: Box::new(self.qualify(element)),
+            },
+            TypeAst::Tuple { types } => TypeAst::Tuple {
+                types: qualify_all(types),
+            },
+            TypeAst::Variadic { inner } => TypeAst::Variadic {
+                inner: Box::new(self.qualify(inner)),
+            },
+            TypeAst::Literal { .. } | TypeAst::Typeof { .. } | TypeAst::GenericPack { .. } => {
+                type_ast.clone()
+            }
+        }
+    }
+
+    /// Converts a type into lua-language-server's syntax. Types that couldn't be parsed are kept
+    /// as they were written.
+    fn stub_type(&self, type_ast: Option<&TypeAst>, lua_type: &str) -> String {
+        match (type_ast, lua_type.trim()) {
+            (Some(type_ast), _) => render_type(&self.qualify(type_ast)),
+            (None, "") => "any".to_owned(),
+            (None, lua_type) => lua_type.to_owned(),
+        }
+    }
+}
+
+fn class_stub(class: &OutputClass) -> String {
+    let entry = &class.class;
+    let local = local_name(&entry.name);
+    let types = ClassTypes::new(class);
+
+    let mut lines = vec!["---@meta".to_owned(), String::new()];
+
+    lines.extend(description(&entry.desc));
+
+    if let Some(deprecated) = &entry.deprecated {
+        lines.push(deprecated_annotation(
+            deprecated.desc.map(|desc| desc.as_str()),
+        ));
+    }
+
+    lines.push(match &entry.extends {
+        Some(parent) => format!("---@class {} : {}", entry.name, parent.as_str()),
+        None => format!("---@class {}", entry.name),
+    });
+
+    // Inherited members are left to `---@class Name : Parent`
+    let properties = class
+        .properties
+        .iter()
+        .filter(|property| !property.ignore && property.inherited_from.is_none());
+
+    lines.extend(properties.map(|property| field_annotation(property, &types)));
+    lines.push(format!("local {} = {{}}", local));
+
+    for type_entry in class.types.iter().filter(|type_entry| !type_entry.ignore) {
+        lines.push(String::new());
+        lines.extend(type_stub(type_entry, &types));
+    }
+
+    let functions = class
+        .functions
+        .iter()
+        .filter(|function| !function.ignore && function.inherited_from.is_none());
+
+    for function in functions {
+        lines.push(String::new());
+        lines.extend(function_stub(function, &local, &types));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("return {}", local));
+    lines.push(String::new());
+
+    lines.join("\n")
+}
+
+/// Turns a description into `---` comment lines, which the language server shows on hover
+fn description(desc: &str) -> Vec<String> {
+    let desc = desc.trim();
+
+    if desc.is_empty() {
+        return Vec::new();
+    }
+
+    desc.lines()
+        .map(|line| match line.trim_end() {
+            "" => "---".to_owned(),
+            line => format!("--- {}", line),
+        })
+        .collect()
+}
+
+/// Annotations only span one line, so their descriptions are joined onto it
+fn inline_description(desc: &str) -> String {
+    desc.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn deprecated_annotation(desc: Option<&str>) -> String {
+    match desc.map(inline_description) {
+        Some(desc) if !desc.is_empty() => format!("---@deprecated {}", desc),
+        _ => "---@deprecated".to_owned(),
+    }
+}
+
+fn with_description(annotation: String, desc: &str) -> String {
+    match inline_description(desc) {
+        desc if desc.is_empty() => annotation,
+        desc => format!("{} {}", annotation, desc),
+    }
+}
+
+fn field_annotation(property: &PropertyDocEntry, types: &ClassTypes) -> String {
+    let scope = if property.private { "private " } else { "" };
+    let lua_type = types.stub_type(property.type_ast.as_ref(), &property.lua_type);
+
+    with_description(
+        format!("---@field {}{} {}", scope, property.name, lua_type),
+        &property.desc,
+    )
+}
+
+fn type_stub(type_entry: &TypeDocEntry, types: &ClassTypes) -> Vec<String> {
+    let name = types.qualified_name(&type_entry.name);
+    let mut lines = description(&type_entry.desc);
+
+    if let Some(deprecated) = &type_entry.deprecated {
+        lines.push(deprecated_annotation(
+            deprecated.desc.map(|desc| desc.as_str()),
+        ));
+    }
+
+    match &type_entry.lua_type {
+        Some(lua_type) if type_entry.fields.is_empty() => lines.push(format!(
+            "---@alias {} {}",
+            name,
+            types.stub_type(type_entry.type_ast.as_ref(), lua_type)
+        )),
+        _ => {
+            lines.push(format!("---@class {}", name));
+            lines.extend(type_entry.fields.iter().map(|field| {
+                with_description(
+                    format!(
+                        "---@field {} {}",
+                        field.name,
+                        types.stub_type(field.type_ast.as_ref(), &field.lua_type)
+                    ),
+                    &field.desc,
+                )
+            }));
+        }
+    }
+
+    lines
+}
+
+fn function_stub(function: &FunctionDocEntry, local: &str, types: &ClassTypes) -> Vec<String> {
+    let mut lines = description(&function.desc);
+
+    // A callback is a signature for users to implement, so it becomes a function type
+    if function.callback {
+        lines.push(format!(
+            "---@alias {} {}",
+            types.qualified_name(&function.name),
+            signature_type(&function.params, &function.returns, types)
+        ));
+
+        return lines;
+    }
+
+    lines.extend(
+        function
+            .params
+            .iter()
+            .map(|param| param_annotation(param, types)),
+    );
+    lines.extend(
+        function
+            .returns
+            .iter()
+            .map(|ret| return_annotation(ret, types)),
+    );
+
+    // The first signature is the function's own
+    lines.extend(function.signatures.iter().skip(1).map(|signature| {
+        format!(
+            "---@overload {}",
+            signature_type(&signature.params, &signature.returns, types)
+        )
+    }));
+
+    if function.yields {
+        lines.push("---@async".to_owned());
+    }
+
+    if let Some(deprecated) = &function.deprecated {
+        lines.push(deprecated_annotation(
+            deprecated.desc.map(|desc| desc.as_str()),
+        ));
+    }
+
+    if function.private {
+        lines.push("---@private".to_owned());
+    }
+
+    let separator = match function.function_type {
+        FunctionType::Method => ":",
+        FunctionType::Static => ".",
+    };
+
+    let params: Vec<&str> = function.params.iter().map(param_name).collect();
+
+    lines.push(format!(
+        "function {}{}{}({}) end",
+        local,
+        separator,
+        function.name,
+        params.join(", ")
+    ));
+
+    lines
+}
+
+fn param_name<'p>(param: &'p FunctionParam) -> &'p str {
+    param.name.trim_end_matches('?')
+}
+
+/// The name and type of a param. Optional params are written as `name?`, so `T?` is written as
+/// `T` on them.
+fn param_parts(param: &FunctionParam, types: &ClassTypes) -> (String, String) {
+    if !param.optional || param.variadic {
+        let lua_type = types.stub_type(param.type_ast.as_ref(), &param.lua_type);
+        return (param_name(param).to_owned(), lua_type);
+    }
+
+    let lua_type = match &param.type_ast {
+        Some(TypeAst::Optional { inner }) => types.stub_type(Some(inner), &param.lua_type),
+        type_ast => types.stub_type(type_ast.as_ref(), &param.lua_type),
+    };
+
+    (format!("{}?", param_name(param)), lua_type)
+}
+
+fn param_annotation(param: &FunctionParam, types: &ClassTypes) -> String {
+    let (name, lua_type) = param_parts(param, types);
+
+    with_description(format!("---@param {} {}", name, lua_type), &param.desc)
+}
+
+fn return_annotation(ret: &FunctionReturn, types: &ClassTypes) -> String {
+    let lua_type = types.stub_type(ret.type_ast.as_ref(), &ret.lua_type);
+
+    match inline_description(&ret.desc) {
+        desc if desc.is_empty() => format!("---@return {}", lua_type),
+        desc => format!("---@return {} # {}", lua_type, desc),
+    }
+}
+
+/// The `fun(...)` type of a function's params and returns
+fn signature_type(
+    params: &[FunctionParam],
+    returns: &[FunctionReturn],
+    types: &ClassTypes,
+) -> String {
+    let params: Vec<String> = params
+        .iter()
+        .map(|param| {
+            let (name, lua_type) = param_parts(param, types);
+            format!("{}: {}", name, lua_type)
+        })
+        .collect();
+
+    let returns: Vec<String> = returns
+        .iter()
+        .map(|ret| types.stub_type(ret.type_ast.as_ref(), &ret.lua_type))
+        .collect();
+
+    function_type(params, returns)
+}
+
+fn function_type(params: Vec<String>, returns: Vec<String>) -> String {
+    match returns.is_empty() {
+        true => format!("fun({})", params.join(", ")),
+        false => format!("fun({}): {}", params.join(", "), returns.join(", ")),
+    }
+}
+
+fn render_types(types: &[TypeAst]) -> Vec<String> {
+    types.iter().map(render_type).collect()
+}
+
+fn render_type(type_ast: &TypeAst) -> String {
+    match type_ast {
+        TypeAst::Reference { name } => name.clone(),
+        TypeAst::Generic { name, generics } => {
+            format!("{}<{}>", name, render_types(generics).join(", "))
+        }
+        TypeAst::Literal { value } => value.clone(),
+        TypeAst::Optional { inner } => format!("{}?", render_operand(inner)),
+        TypeAst::Union { types } => types
+            .iter()
+            .map(render_operand)
+            .collect::<Vec<_>>()
+            .join("|"),
+        // lua-language-server has no intersection types. Intersections are mostly overloaded
+        // functions, so the first one is kept.
+        TypeAst::Intersection { types } => types
+            .first()
+            .map(render_type)
+            .unwrap_or_else(|| "any".to_owned()),
+        TypeAst::Function { params, returns } => function_type(
+            params.iter().enumerate().map(render_param).collect(),
+            render_types(returns),
+        ),
+        TypeAst::Table { fields } => render_table(fields),
+        TypeAst::Array { element } => format!("{}[]", render_operand(element)),
+        TypeAst::Tuple { types } => render_types(types).join(", "),
+        TypeAst::Variadic { inner } => format!("{}...", render_operand(inner)),
+        TypeAst::Typeof { .. } | TypeAst::GenericPack { .. } => "any".to_owned(),
+    }
+}
+
+/// Renders a type that's part of a bigger one, adding parentheses where they're needed, like in
+/// `(string|number)[]`
+fn render_operand(type_ast: &TypeAst) -> String {
+    match type_ast {
+        TypeAst::Optional { .. }
+        | TypeAst::Union { .. }
+        | TypeAst::Intersection { .. }
+        | TypeAst::Function { .. } => format!("({})", render_type(type_ast)),
+        _ => render_type(type_ast),
+    }
+}
+
+/// Function types need a name for every param, so unnamed ones are numbered
+fn render_param((index, param): (usize, &TypeAstParam)) -> String {
+    match (&param.lua_type, &param.name) {
+        (TypeAst::Variadic { inner }, _) => format!("...: {}", render_type(inner)),
+        (lua_type, Some(name)) => format!("{}: {}", name, render_type(lua_type)),
+        (lua_type, None) => format!("arg{}: {}", index + 1, render_type(lua_type)),
+    }
+}
+
+/// `{ [K]: V }` is written as `table<K, V>`, and other tables as `{ key: T }`
+fn render_table(fields: &[TypeAstField]) -> String {
+    if let [TypeAstField {
+        indexer: Some(key),
+        value,
+        ..
+    }] = fields
+    {
+        return format!("table<{}, {}>", render_type(key), render_type(value));
+    }
+
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|field| match (&field.name, &field.indexer) {
+            (Some(name), _) => format!("{}: {}", name, render_type(&field.value)),
+            (None, Some(key)) => format!("[{}]: {}", render_type(key), render_type(&field.value)),
+            (None, None) => render_type(&field.value),
+        })
+        .collect();
+
+    match fields.is_empty() {
+        true => "table".to_owned(),
+        false => format!("{{ {} }}", fields.join(", ")),
+    }
+}
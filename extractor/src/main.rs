@@ -1,21 +1,43 @@
-use libmoonwave::{generate_docs_from_path, Args, Subcommand};
-use std::env::current_dir;
+use libmoonwave::{
+    generate_docs_from_path, generate_luals_stubs, generate_selene_std, Args, ExtractOptions,
+    Subcommand,
+};
+use std::{env::current_dir, path::PathBuf};
 use structopt::StructOpt;
 
+/// Uses the current directory if no input paths were given, unless the files to extract come from
+/// stdin or a list
+fn input_paths(paths: Vec<PathBuf>, options: &ExtractOptions) -> anyhow::Result<Vec<PathBuf>> {
+    let has_other_input = options.stdin_filename.is_some() || options.files_from.is_some();
+
+    match paths.is_empty() && !has_other_input {
+        true => Ok(vec![current_dir()?]),
+        false => Ok(paths),
+    }
+}
+
 fn run(args: Args) -> anyhow::Result<()> {
     match args.subcommand {
         Subcommand::Extract(subcommand) => {
-            let path = match subcommand.input_path {
-                Some(path) => path,
-                None => current_dir()?,
-            };
+            let paths = input_paths(subcommand.input_paths, &subcommand.options)?;
 
-            let base_path = match subcommand.base_path {
-                Some(path) => path,
-                None => path.clone(),
+            let base_path = match (subcommand.base_path, paths.as_slice()) {
+                (Some(path), _) => path,
+                (None, [path]) => path.clone(),
+                (None, _) => current_dir()?,
             };
 
-            generate_docs_from_path(&path, &base_path)
+            generate_docs_from_path(&paths, &base_path, &subcommand.output, &subcommand.options)
+        }
+        Subcommand::EmitLuals(subcommand) => {
+            let paths = input_paths(subcommand.input_paths, &subcommand.options)?;
+
+            generate_luals_stubs(&paths, &subcommand.out_dir, &subcommand.options)
+        }
+        Subcommand::EmitSelene(subcommand) => {
+            let paths = input_paths(subcommand.input_paths, &subcommand.options)?;
+
+            generate_selene_std(&paths, &subcommand.name, &subcommand.options)
         }
     }
 }
@@ -0,0 +1,56 @@
+//! Passes that run over the assembled classes after every doc entry has been parsed
+
+mod check_annotations;
+mod check_code_blocks;
+mod check_implements;
+mod map_data_model_paths;
+mod parse_types;
+mod resolve_inheritance;
+mod resolve_links;
+mod resolve_types;
+mod roblox_classes;
+
+pub use check_annotations::check_annotations;
+pub use check_code_blocks::check_code_blocks;
+pub use check_implements::check_implements;
+pub use map_data_model_paths::map_data_model_paths;
+pub use parse_types::parse_types;
+pub use resolve_inheritance::resolve_inheritance;
+pub use resolve_links::resolve_links;
+pub use resolve_types::resolve_types;
+
+use crate::{diagnostic::Diagnostic, doc_comment::DocComment, span::Span};
+
+/// Creates a diagnostic at the tag something was documented with, or at its whole doc comment if
+/// it came from the source code instead
+fn diagnostic_at(text: String, tag_source: Option<Span>, doc_comment: &DocComment) -> Diagnostic {
+    match tag_source {
+        Some(span) => span.diagnostic(text),
+        None => doc_comment.diagnostic(text),
+    }
+}
+
+/// Finds the candidate closest to a misspelled name, if any are close enough to be a typo
+fn suggestion<'n>(name: &str, candidates: impl Iterator<Item = &'n str>) -> Option<&'n str> {
+    let name = name.to_lowercase();
+    let max_distance = (name.len() / 3).max(1);
+
+    candidates
+        .map(|candidate| {
+            (
+                strsim::osa_distance(&name, &candidate.to_lowercase()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Adds a "did you mean" hint to the text of a diagnostic if there's a suggestion
+fn did_you_mean(text: String, suggestion: Option<&str>) -> String {
+    match suggestion {
+        Some(suggestion) => format!("{}. Did you mean \"{}\"?", text, suggestion),
+        None => text,
+    }
+}
@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    diagnostic::Diagnostic,
+    doc_entry::FunctionDocEntry,
+    type_ast::{TypeAst, TypeAstField, TypeAstParam},
+    OutputClass,
+};
+
+/// How many aliases deep to expand before giving up, in case an alias refers to itself
+const MAX_ALIAS_DEPTH: usize = 8;
+
+/// Documented type aliases, keyed by their `Class.Type` name, along with the class they're in
+pub(super) type Aliases<'c> = BTreeMap<String, (&'c str, &'c TypeAst)>;
+
+/// Drops the qualifying module from a type name, so `Module.Type` matches `Type`
+fn unqualified(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn sorted(mut types: Vec<TypeAst>) -> Vec<TypeAst> {
+    types.sort_by_cached_key(|lua_type| format!("{:?}", lua_type));
+    types.dedup();
+    types
+}
+
+/// Finds the alias a type name refers to. Plain names refer to the types of the class they're
+/// written in.
+fn find_alias<'c>(
+    name: &str,
+    class: &str,
+    aliases: &Aliases<'c>,
+) -> Option<(&'c str, &'c TypeAst)> {
+    match name.contains('.') {
+        true => aliases.get(name).copied(),
+        false => aliases.get(&format!("{}.{}", class, name)).copied(),
+    }
+}
+
+/// Rewrites a type written in a class so that types that only differ in spelling compare equal.
+/// Aliases are expanded, names are unqualified, `T?` becomes `T | nil`, parameter names are
+/// dropped, and the members of unions, intersections and tables are sorted.
+fn normalize(lua_type: &TypeAst, class: &str, aliases: &Aliases, depth: usize) -> TypeAst {
+    let normalize_all = |types: &[TypeAst]| -> Vec<TypeAst> {
+        types
+            .iter()
+            .map(|inner| normalize(inner, class, aliases, depth))
+            .collect()
+    };
+
+    match lua_type {
+        TypeAst::Reference { name } => match find_alias(name, class, aliases) {
+            Some((alias_class, alias)) if depth < MAX_ALIAS_DEPTH => {
+                normalize(alias, alias_class, aliases, depth + 1)
+            }
+            _ => TypeAst::Reference {
+                name: unqualified(name).to_owned(),
+            },
+        },
+        TypeAst::Generic { name, generics } => TypeAst::Generic {
+            name: unqualified(name).to_owned(),
+            generics: normalize_all(generics),
+        },
+        TypeAst::Optional { inner } => normalize(
+            &TypeAst::Union {
+                types: vec![
+                    inner.as_ref().clone(),
+                    TypeAst::Reference {
+                        name: "nil".to_owned(),
+                    },
+                ],
+            },
+            class,
+            aliases,
+            depth,
+        ),
+        TypeAst::Union { types } => {
+            let mut members = Vec::new();
+
+            for member in normalize_all(types) {
+                match member {
+                    TypeAst::Union { types } => members.extend(types),
+                    member => members.push(member),
+                }
+            }
+
+            TypeAst::Union {
+                types: sorted(members),
+            }
+        }
+        TypeAst::Intersection { types } => TypeAst::Intersection {
+            types: sorted(normalize_all(types)),
+        },
+        TypeAst::Function { params, returns } => TypeAst::Function {
+            params: params
+                .iter()
+                .map(|param| TypeAstParam {
+                    name: None,
+                    lua_type: normalize(&param.lua_type, class, aliases, depth),
+                })
+                .collect(),
+            returns: normalize_all(returns),
+        },
+        TypeAst::Table { fields } => {
+            let mut fields: Vec<TypeAstField> = fields
+                .iter()
+                .map(|field| TypeAstField {
+                    name: field.name.clone(),
+                    indexer: field
+                        .indexer
+                        .as_ref()
+                        .map(|indexer| normalize(indexer, class, aliases, depth)),
+                    value: normalize(&field.value, class, aliases, depth),
+                })
+                .collect();
+
+            fields.sort_by_cached_key(|field| format!("{:?}", field));
+
+            TypeAst::Table { fields }
+        }
+        TypeAst::Array { element } => TypeAst::Array {
+            element: Box::new(normalize(element, class, aliases, depth)),
+        },
+        TypeAst::Tuple { types } => TypeAst::Tuple {
+            types: normalize_all(types),
+        },
+        TypeAst::Variadic { inner } => TypeAst::Variadic {
+            inner: Box::new(normalize(inner, class, aliases, depth)),
+        },
+        TypeAst::Literal { .. } | TypeAst::Typeof { .. } | TypeAst::GenericPack { .. } => {
+            lua_type.clone()
+        }
+    }
+}
+
+/// Whether two type strings describe the same type, given the classes each is written in. Types
+/// that can't be parsed are compared with their whitespace removed.
+pub(super) fn same_type(
+    (documented, documented_class): (&str, &str),
+    (annotated, annotated_class): (&str, &str),
+    aliases: &Aliases,
+) -> bool {
+    match (TypeAst::parse(documented), TypeAst::parse(annotated)) {
+        (Ok(documented), Ok(annotated)) => same_type_ast(
+            (&documented, documented_class),
+            (&annotated, annotated_class),
+            aliases,
+        ),
+        _ => {
+            let strip = |lua_type: &str| lua_type.split_whitespace().collect::<String>();
+
+            strip(documented) == strip(annotated)
+        }
+    }
+}
+
+/// Whether two parsed types describe the same type, given the classes each is written in
+pub(super) fn same_type_ast(
+    (documented, documented_class): (&TypeAst, &str),
+    (annotated, annotated_class): (&TypeAst, &str),
+    aliases: &Aliases,
+) -> bool {
+    normalize(documented, documented_class, aliases, 0)
+        == normalize(annotated, annotated_class, aliases, 0)
+}
+
+fn check_function(class: &str, function: &FunctionDocEntry, aliases: &Aliases) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for param in &function.params {
+        if let (Some(annotated), Some(tag_source)) = (&param.annotated_type, param.tag_source) {
+            if !same_type((&param.lua_type, class), (annotated, class), aliases) {
+                diagnostics.push(
+                    tag_source
+                        .diagnostic(format!(
+                            "Param \"{}\" is documented as \"{}\", but its Luau annotation is \"{}\"",
+                            param.name,
+                            param.lua_type.trim(),
+                            annotated.trim()
+                        ))
+                        .into_warning(),
+                );
+            }
+        }
+    }
+
+    let annotated_returns = &function.annotated_returns;
+    let is_variadic = |lua_type: &String| {
+        let lua_type = lua_type.trim();
+        lua_type.starts_with("...") || lua_type.ends_with("...")
+    };
+
+    if annotated_returns.is_empty() || annotated_returns.iter().any(is_variadic) {
+        return diagnostics;
+    }
+
+    if annotated_returns.len() != function.returns.len() {
+        if let Some(tag_source) = function.returns.first().and_then(|ret| ret.tag_source) {
+            diagnostics.push(
+                tag_source
+                    .diagnostic(format!(
+                        "{} return values are documented, but the Luau annotation returns {}",
+                        function.returns.len(),
+                        annotated_returns.len()
+                    ))
+                    .into_warning(),
+            );
+        }
+
+        return diagnostics;
+    }
+
+    for (ret, annotated) in function.returns.iter().zip(annotated_returns) {
+        if let Some(tag_source) = ret.tag_source {
+            if !same_type((&ret.lua_type, class), (annotated, class), aliases) {
+                diagnostics.push(
+                    tag_source
+                        .diagnostic(format!(
+                            "Return value is documented as \"{}\", but its Luau annotation is \"{}\"",
+                            ret.lua_type.trim(),
+                            annotated.trim()
+                        ))
+                        .into_warning(),
+                );
+            }
+        }
+    }
+
+    diagnostics
+}
+
+/// Collects the documented type aliases of every class
+pub(super) fn aliases<'c>(classes: &'c [OutputClass]) -> Aliases<'c> {
+    let mut aliases = Aliases::new();
+
+    for class in classes {
+        for type_entry in &class.types {
+            if let Some(type_ast) = &type_entry.type_ast {
+                aliases.insert(
+                    format!("{}.{}", class.class.name, type_entry.name),
+                    (class.class.name.as_str(), type_ast),
+                );
+            }
+        }
+    }
+
+    aliases
+}
+
+/// Reports `@param` and `@return` tags whose types disagree with the Luau annotations they
+/// replace
+pub fn check_annotations(classes: &[OutputClass]) -> Vec<Diagnostic> {
+    let aliases = aliases(classes);
+
+    classes
+        .iter()
+        .flat_map(|class| {
+            class
+                .functions
+                .iter()
+                .flat_map(|function| check_function(&class.class.name, function, &aliases))
+        })
+        .collect()
+}
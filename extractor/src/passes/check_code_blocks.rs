@@ -0,0 +1,177 @@
+use std::mem;
+
+use crate::{diagnostic::Diagnostic, dialect::Dialect, span::Span, tags::ExampleTag, OutputClass};
+
+/// A fence that has been opened, along with the lines inside of it so far
+struct OpenFence<'a> {
+    marker: &'static str,
+    dialect: Option<Dialect>,
+    lines: Vec<Span<'a>>,
+}
+
+/// The dialect a code block's language is parsed as. ```luau blocks are always Luau, while
+/// ```lua blocks are in the dialect of the source files.
+fn block_dialect(language: &str, dialect: Dialect) -> Option<Dialect> {
+    match language {
+        "lua" => Some(dialect),
+        "luau" => Some(Dialect::Luau),
+        _ => None,
+    }
+}
+
+/// Finds the lines of every fenced ```lua and ```luau code block in a description, along with the
+/// dialect to parse them as. Blocks with `ignore` in their info string, like ```lua ignore, are
+/// skipped.
+fn find_code_blocks<'a>(
+    desc_lines: &[Span<'a>],
+    dialect: Dialect,
+) -> Vec<(Dialect, Vec<Span<'a>>)> {
+    let mut blocks = Vec::new();
+    let mut open_fence: Option<OpenFence> = None;
+
+    for line in desc_lines {
+        let trimmed = line.as_str().trim_start();
+
+        match &mut open_fence {
+            Some(fence) => {
+                if trimmed.starts_with(fence.marker)
+                    && trimmed
+                        .trim_start_matches(&fence.marker[..1])
+                        .trim()
+                        .is_empty()
+                {
+                    if let Some(dialect) = fence.dialect {
+                        blocks.push((dialect, mem::take(&mut fence.lines)));
+                    }
+
+                    open_fence = None;
+                } else {
+                    fence.lines.push(*line);
+                }
+            }
+            None => {
+                let marker = if trimmed.starts_with("```") {
+                    "```"
+                } else if trimmed.starts_with("~~~") {
+                    "~~~"
+                } else {
+                    continue;
+                };
+
+                let mut info = trimmed
+                    .trim_start_matches(&marker[..1])
+                    .split(|char: char| char.is_whitespace() || char == ',')
+                    .filter(|word| !word.is_empty());
+
+                open_fence = Some(OpenFence {
+                    marker,
+                    dialect: info
+                        .next()
+                        .and_then(|language| block_dialect(language, dialect))
+                        .filter(|_| !info.any(|word| word == "ignore")),
+                    lines: Vec::new(),
+                });
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Maps a byte range of the code in a block back to where it is in the doc comment
+fn span_in_block<'a>(lines: &[Span<'a>], start: usize, end: usize) -> Option<Span<'a>> {
+    let mut line_start = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_end = line_start + line.len;
+
+        if start <= line_end || index == lines.len() - 1 {
+            let start = start.saturating_sub(line_start).min(line.len);
+            let end = end.saturating_sub(line_start).clamp(start, line.len);
+
+            return Some(line.slice(start, end - start));
+        }
+
+        // Skip over the newline the lines were joined with
+        line_start = line_end + 1;
+    }
+
+    None
+}
+
+fn check_block(lines: &[Span], dialect: Dialect, diagnostics: &mut Vec<Diagnostic>) {
+    let code = lines
+        .iter()
+        .map(Span::as_str)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Errors after the first are usually caused by the first one, so they aren't reported
+    let error = match full_moon::parse_fallible(&code, dialect.lua_version()).into_result() {
+        Err(errors) if !errors.is_empty() => errors.into_iter().next().unwrap(),
+        _ => return,
+    };
+
+    let (start, end) = error.range();
+
+    if let Some(span) = span_in_block(lines, start.bytes(), end.bytes()) {
+        diagnostics.push(
+            span.diagnostic(format!(
+                "Syntax error in code block: {}",
+                error.error_message()
+            ))
+            .into_warning(),
+        );
+    }
+}
+
+fn check_desc(
+    desc_lines: &[Span],
+    examples: &[ExampleTag],
+    dialect: Dialect,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for (dialect, lines) in find_code_blocks(desc_lines, dialect) {
+        check_block(&lines, dialect, diagnostics);
+    }
+
+    for example in examples.iter().filter(|example| !example.ignore) {
+        if let Some(dialect) = block_dialect(&example.language, dialect) {
+            check_block(&example.code_lines, dialect, diagnostics);
+        }
+    }
+}
+
+/// Reports syntax errors in the Lua code blocks of every description and example
+pub fn check_code_blocks(classes: &[OutputClass], dialect: Dialect) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut check = |desc_lines: &[Span], examples: &[ExampleTag]| {
+        check_desc(desc_lines, examples, dialect, &mut diagnostics)
+    };
+
+    for class in classes {
+        check(&class.class.desc_spans, &class.class.examples);
+
+        for function in &class.functions {
+            check(&function.desc_spans, &function.examples);
+
+            for signature in function.signatures.iter().skip(1) {
+                check(&signature.desc_spans, &[]);
+            }
+        }
+
+        for property in &class.properties {
+            check(&property.desc_spans, &property.examples);
+        }
+
+        for event in &class.events {
+            check(&event.desc_spans, &event.examples);
+        }
+
+        for type_entry in &class.types {
+            check(&type_entry.desc_spans, &type_entry.examples);
+        }
+    }
+
+    diagnostics
+}
@@ -0,0 +1,267 @@
+use super::{
+    check_annotations::{aliases, same_type, same_type_ast, Aliases},
+    diagnostic_at, did_you_mean, suggestion,
+};
+use crate::{
+    diagnostic::Diagnostic,
+    doc_entry::{Field, FunctionDocEntry, FunctionType, TypeDocEntry},
+    span::Span,
+    type_ast::{TypeAst, TypeAstParam},
+    OutputClass,
+};
+
+/// Finds the type an `@implements` tag refers to, either as `Class.Type` or as a plain type name,
+/// along with the name of the class it's in. Plain names prefer the types within the implementing
+/// class.
+fn find_interface<'c, 'a>(
+    name: &str,
+    class: &OutputClass,
+    classes: &'c [OutputClass<'a>],
+) -> Option<(&'c str, &'c TypeDocEntry<'a>)> {
+    let with_class = |candidate: &'c OutputClass<'a>| {
+        candidate
+            .types
+            .iter()
+            .map(move |type_entry| (candidate.class.name.as_str(), type_entry))
+    };
+
+    if let Some((class_name, type_name)) = name.rsplit_once('.') {
+        return classes
+            .iter()
+            .filter(|candidate| candidate.class.name == class_name)
+            .flat_map(with_class)
+            .find(|(_, type_entry)| type_entry.name == type_name);
+    }
+
+    let own_class = classes
+        .iter()
+        .filter(|candidate| candidate.class.name == class.class.name);
+    let other_classes = classes
+        .iter()
+        .filter(|candidate| candidate.class.name != class.class.name);
+
+    own_class
+        .chain(other_classes)
+        .flat_map(with_class)
+        .find(|(_, type_entry)| type_entry.name == name)
+}
+
+/// Whether a field's type can't possibly describe a function
+fn is_data_type(field: &Field) -> bool {
+    matches!(
+        field.type_ast,
+        Some(TypeAst::Table { .. } | TypeAst::Array { .. } | TypeAst::Literal { .. })
+    )
+}
+
+/// The function type of a function's params and returns, like `(name: string) -> boolean`, if all
+/// of their types could be parsed
+fn function_type(function: &FunctionDocEntry) -> Option<TypeAst> {
+    let params = function
+        .params
+        .iter()
+        .map(|param| {
+            let lua_type = param.type_ast.clone()?;
+
+            let lua_type = match lua_type {
+                TypeAst::Variadic { .. } => lua_type,
+                lua_type if param.variadic => TypeAst::Variadic {
+                    inner: Box::new(lua_type),
+                },
+                TypeAst::Optional { .. } => lua_type,
+                lua_type if param.name.ends_with('?') => TypeAst::Optional {
+                    inner: Box::new(lua_type),
+                },
+                lua_type => lua_type,
+            };
+
+            Some(TypeAstParam {
+                name: None,
+                lua_type,
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    let returns = function
+        .returns
+        .iter()
+        .map(|ret| ret.type_ast.clone())
+        .collect::<Option<_>>()?;
+
+    Some(TypeAst::Function { params, returns })
+}
+
+/// How a function's signature is written, for diagnostics
+fn signature_text(function: &FunctionDocEntry) -> String {
+    let params: Vec<String> = function
+        .params
+        .iter()
+        .map(|param| match param.variadic {
+            true => format!("...{}", param.lua_type.trim()),
+            false => format!("{}: {}", param.name, param.lua_type.trim()),
+        })
+        .collect();
+
+    let returns: Vec<&str> = function
+        .returns
+        .iter()
+        .map(|ret| ret.lua_type.trim())
+        .collect();
+
+    let returns = match returns.as_slice() {
+        [ret] => ret.to_string(),
+        returns => format!("({})", returns.join(", ")),
+    };
+
+    format!("({}) -> {}", params.join(", "), returns)
+}
+
+/// Whether a function written in `class` matches the function type of an interface field written
+/// in `interface_class`. Methods are compared without the interface's `self` parameter. Fields
+/// that aren't plain function types, like overloads or aliases, aren't compared.
+fn matches_function_type(
+    (function, class): (&FunctionDocEntry, &str),
+    (field, interface_class): (&Field, &str),
+    aliases: &Aliases,
+) -> bool {
+    let (Some(TypeAst::Function { params, returns }), Some(function_type)) =
+        (&field.type_ast, function_type(function))
+    else {
+        return true;
+    };
+
+    let params = match function.function_type {
+        FunctionType::Method => params.iter().skip(1).cloned().collect(),
+        FunctionType::Static => params.clone(),
+    };
+
+    let expected = TypeAst::Function {
+        params,
+        returns: returns.clone(),
+    };
+
+    same_type_ast(
+        (&function_type, class),
+        (&expected, interface_class),
+        aliases,
+    )
+}
+
+fn check_interface(
+    class: &OutputClass,
+    name: Span,
+    (interface_class, interface): (&str, &TypeDocEntry),
+    aliases: &Aliases,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if interface.fields.is_empty() {
+        diagnostics.push(name.diagnostic(format!(
+            "Type \"{}\" has no fields, so it can't be implemented. Use @interface to document one",
+            name.as_str()
+        )));
+
+        return;
+    }
+
+    let mut missing = Vec::new();
+
+    for field in &interface.fields {
+        if let Some(property) = class.properties.iter().find(|p| p.name == field.name) {
+            // Inherited members are written in the class they're inherited from
+            let property_class = property
+                .inherited_from
+                .as_deref()
+                .unwrap_or(&class.class.name);
+
+            if !same_type(
+                (&property.lua_type, property_class),
+                (&field.lua_type, interface_class),
+                aliases,
+            ) {
+                let text = format!(
+                    "Property \"{}\" is documented as \"{}\", but interface \"{}\" expects \"{}\"",
+                    property.name,
+                    property.lua_type.trim(),
+                    name.as_str(),
+                    field.lua_type.trim()
+                );
+
+                diagnostics
+                    .push(diagnostic_at(text, property.tag_source, property.source).into_warning());
+            }
+        } else if let Some(function) = class.functions.iter().find(|f| f.name == field.name) {
+            let function_class = function
+                .inherited_from
+                .as_deref()
+                .unwrap_or(&class.class.name);
+
+            if is_data_type(field) {
+                let text = format!(
+                    "\"{}\" is documented as a function, but interface \"{}\" expects \"{}\"",
+                    function.name,
+                    name.as_str(),
+                    field.lua_type.trim()
+                );
+
+                diagnostics.push(function.source.diagnostic(text).into_warning());
+            } else if !matches_function_type(
+                (function, function_class),
+                (field, interface_class),
+                aliases,
+            ) {
+                let text = format!(
+                    "\"{}\" is documented as \"{}\", but interface \"{}\" expects \"{}\"",
+                    function.name,
+                    signature_text(function),
+                    name.as_str(),
+                    field.lua_type.trim()
+                );
+
+                diagnostics.push(function.source.diagnostic(text).into_warning());
+            }
+        } else {
+            missing.push(format!("\"{}\"", field.name));
+        }
+    }
+
+    if !missing.is_empty() {
+        diagnostics.push(name.diagnostic(format!(
+            "Class \"{}\" is missing {} from interface \"{}\"",
+            class.class.name,
+            missing.join(", "),
+            name.as_str()
+        )));
+    }
+}
+
+/// Checks that every class documents a property or function for each field of the interfaces it
+/// `@implements`. Missing members are errors, and members whose types disagree are warnings.
+pub fn check_implements(classes: &[OutputClass]) -> Vec<Diagnostic> {
+    let aliases = aliases(classes);
+    let mut diagnostics = Vec::new();
+
+    for class in classes {
+        for name in &class.class.implements {
+            match find_interface(name.as_str(), class, classes) {
+                Some(interface) => {
+                    check_interface(class, *name, interface, &aliases, &mut diagnostics)
+                }
+                None => {
+                    let text = format!("Interface \"{}\" is missing a doc entry", name.as_str());
+
+                    let candidates = classes
+                        .iter()
+                        .flat_map(|class| &class.types)
+                        .filter(|type_entry| !type_entry.fields.is_empty())
+                        .map(|type_entry| type_entry.name.as_str());
+
+                    diagnostics.push(
+                        name.diagnostic(did_you_mean(text, suggestion(name.as_str(), candidates))),
+                    );
+                }
+            }
+        }
+    }
+
+    diagnostics
+}
@@ -0,0 +1,40 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
+
+use crate::{diagnostic::Diagnostic, rojo::RojoProject, OutputClass};
+
+/// Sets the DataModel path of each class from the file it's in. Classes in files that the Rojo
+/// project doesn't sync get a warning, once for each file.
+pub fn map_data_model_paths(
+    classes: &mut [OutputClass],
+    project: &RojoProject,
+    file_paths: &HashMap<usize, PathBuf>,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let mut warned_files = HashSet::new();
+
+    for class in classes.iter_mut() {
+        let source = class.class.source;
+
+        let Some(file_path) = file_paths.get(&source.file_id) else {
+            continue;
+        };
+
+        match project.data_model_path(file_path) {
+            Some(path) => class.class.data_model_path = Some(path),
+            None if warned_files.insert(source.file_id) => diagnostics.push(
+                source
+                    .diagnostic(
+                        "This file isn't part of the Rojo project, so its classes have no \
+                        DataModel path",
+                    )
+                    .into_warning(),
+            ),
+            None => {}
+        }
+    }
+
+    diagnostics
+}
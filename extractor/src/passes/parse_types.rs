@@ -0,0 +1,106 @@
+use super::diagnostic_at;
+use crate::{
+    diagnostic::Diagnostic, doc_comment::DocComment, span::Span, type_ast::TypeAst, OutputClass,
+};
+
+/// Parses a type string, reporting a warning if it isn't valid Luau. The warning points at the
+/// offending text when the type can be found in the tag it was written in.
+fn parse_type(
+    lua_type: &str,
+    tag_source: Option<Span>,
+    doc_comment: &DocComment,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Option<TypeAst> {
+    if lua_type.trim().is_empty() {
+        return None;
+    }
+
+    match TypeAst::parse(lua_type) {
+        Ok(type_ast) => Some(type_ast),
+        Err(error) => {
+            let text = format!("Could not parse type \"{}\": {}", lua_type.trim(), error);
+
+            let error_source = tag_source.and_then(|span| {
+                let start = span.as_str().find(lua_type)?;
+                Some(span.slice(start + error.offset, error.len.max(1)))
+            });
+
+            let diagnostic = match error_source {
+                Some(span) => span.diagnostic(text),
+                None => diagnostic_at(text, tag_source, doc_comment),
+            };
+
+            diagnostics.push(diagnostic.into_warning());
+
+            None
+        }
+    }
+}
+
+/// Fills in the `type_ast` of every type string in the given classes
+pub fn parse_types(classes: &mut [OutputClass]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for class in classes {
+        for function in &mut class.functions {
+            let source = function.source;
+
+            for param in function.all_params_mut() {
+                param.type_ast =
+                    parse_type(&param.lua_type, param.tag_source, source, &mut diagnostics);
+            }
+
+            for ret in function.all_returns_mut() {
+                ret.type_ast = parse_type(&ret.lua_type, ret.tag_source, source, &mut diagnostics);
+            }
+
+            // The first signature of an overloaded function is a copy of its own params and returns
+            if let Some(signature) = function.signatures.first_mut() {
+                signature.params.clone_from(&function.params);
+                signature.returns.clone_from(&function.returns);
+            }
+        }
+
+        for property in &mut class.properties {
+            property.type_ast = parse_type(
+                &property.lua_type,
+                property.tag_source,
+                property.source,
+                &mut diagnostics,
+            );
+        }
+
+        for event in &mut class.events {
+            for param in &mut event.params {
+                param.type_ast = parse_type(
+                    &param.lua_type,
+                    param.tag_source,
+                    event.source,
+                    &mut diagnostics,
+                );
+            }
+        }
+
+        for type_entry in &mut class.types {
+            if let Some(lua_type) = &type_entry.lua_type {
+                type_entry.type_ast = parse_type(
+                    lua_type,
+                    type_entry.tag_source,
+                    type_entry.source,
+                    &mut diagnostics,
+                );
+            }
+
+            for field in &mut type_entry.fields {
+                field.type_ast = parse_type(
+                    &field.lua_type,
+                    Some(field.tag_source),
+                    type_entry.source,
+                    &mut diagnostics,
+                );
+            }
+        }
+    }
+
+    diagnostics
+}
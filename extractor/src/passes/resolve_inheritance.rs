@@ -0,0 +1,140 @@
+use std::collections::BTreeMap;
+
+use super::{did_you_mean, suggestion};
+use crate::{
+    diagnostic::Diagnostic,
+    doc_entry::{EventDocEntry, FunctionDocEntry, PropertyDocEntry},
+    OutputClass,
+};
+
+/// The members of a class that subclasses inherit
+type Members<'a> = (
+    Vec<FunctionDocEntry<'a>>,
+    Vec<PropertyDocEntry<'a>>,
+    Vec<EventDocEntry<'a>>,
+);
+
+/// Walks up the `@extends` chain of a class, returning its ancestors from nearest to furthest.
+/// Unknown parents and cycles end the chain, and are reported by the class that declares them.
+fn ancestors(
+    class: &OutputClass,
+    classes: &[OutputClass],
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Vec<String> {
+    let class_name = class.class.name.as_str();
+    let mut chain: Vec<String> = Vec::new();
+    let mut extends = class.class.extends;
+
+    while let Some(parent) = extends {
+        let parent_name = parent.as_str();
+
+        if parent_name == class_name {
+            let mut path = vec![class_name];
+            path.extend(chain.iter().map(String::as_str));
+            path.push(class_name);
+
+            diagnostics.push(parent.diagnostic(format!(
+                "Class \"{}\" inherits from itself: {}",
+                class_name,
+                path.join(" -> ")
+            )));
+
+            break;
+        }
+
+        if chain.iter().any(|ancestor| ancestor == parent_name) {
+            break;
+        }
+
+        match classes
+            .iter()
+            .find(|candidate| candidate.class.name == parent_name)
+        {
+            Some(parent_class) => {
+                chain.push(parent_name.to_owned());
+                extends = parent_class.class.extends;
+            }
+            None => {
+                if chain.is_empty() {
+                    let text = format!("Parent class \"{}\" is missing a doc entry", parent_name);
+
+                    let candidates = classes.iter().map(|class| class.class.name.as_str());
+
+                    diagnostics.push(
+                        parent.diagnostic(did_you_mean(text, suggestion(parent_name, candidates))),
+                    );
+                }
+
+                break;
+            }
+        }
+    }
+
+    chain
+}
+
+/// Fills in the `ancestors` of every class that uses `@extends`, and copies the functions,
+/// properties and events it inherits into it. Members the class documents itself take precedence.
+pub fn resolve_inheritance(classes: &mut [OutputClass]) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    let chains: Vec<Vec<String>> = classes
+        .iter()
+        .map(|class| ancestors(class, classes, &mut diagnostics))
+        .collect();
+
+    if chains.iter().all(Vec::is_empty) {
+        return diagnostics;
+    }
+
+    let members: BTreeMap<String, Members> = classes
+        .iter()
+        .map(|class| {
+            (
+                class.class.name.clone(),
+                (
+                    class.functions.clone(),
+                    class.properties.clone(),
+                    class.events.clone(),
+                ),
+            )
+        })
+        .collect();
+
+    for (class, chain) in classes.iter_mut().zip(chains) {
+        for ancestor in &chain {
+            let (functions, properties, events) = &members[ancestor];
+
+            for function in functions {
+                if class.functions.iter().all(|own| own.name != function.name) {
+                    class.functions.push(FunctionDocEntry {
+                        inherited_from: Some(ancestor.clone()),
+                        ..function.clone()
+                    });
+                }
+            }
+
+            for property in properties {
+                if class.properties.iter().all(|own| own.name != property.name) {
+                    class.properties.push(PropertyDocEntry {
+                        inherited_from: Some(ancestor.clone()),
+                        ..property.clone()
+                    });
+                }
+            }
+
+            for event in events {
+                if class.events.iter().all(|own| own.name != event.name) {
+                    class.events.push(EventDocEntry {
+                        inherited_from: Some(ancestor.clone()),
+                        ..event.clone()
+                    });
+                }
+            }
+        }
+
+        class.ancestors = chain;
+    }
+
+    diagnostics
+}
@@ -0,0 +1,404 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{did_you_mean, resolve_types::builtin_types, suggestion};
+use crate::{diagnostic::Diagnostic, span::Span, OutputClass};
+
+/// Everything a reference in a description can link to
+struct LinkTargets {
+    /// Class names, along with the names of their functions, properties, types and events
+    classes: BTreeMap<String, BTreeSet<String>>,
+    /// Type names, along with the class they're within
+    types: BTreeMap<String, String>,
+    /// `@external` type names, along with their url
+    externals: BTreeMap<String, String>,
+    /// Types that exist, but which we don't know a link for
+    builtins: BTreeSet<String>,
+}
+
+impl LinkTargets {
+    fn new(classes: &[OutputClass], known_types: &[String]) -> Self {
+        let mut targets = Self {
+            classes: BTreeMap::new(),
+            types: BTreeMap::new(),
+            externals: BTreeMap::new(),
+            builtins: builtin_types(known_types).map(str::to_owned).collect(),
+        };
+
+        for class in classes {
+            let class_name = &class.class.name;
+
+            let members = class
+                .functions
+                .iter()
+                .map(|function| function.name.clone())
+                .chain(
+                    class
+                        .properties
+                        .iter()
+                        .map(|property| property.name.clone()),
+                )
+                .chain(class.types.iter().map(|type_entry| type_entry.name.clone()))
+                .chain(class.events.iter().map(|event| event.name.clone()))
+                .collect();
+
+            targets.classes.insert(class_name.clone(), members);
+
+            for type_entry in &class.types {
+                targets
+                    .types
+                    .insert(type_entry.name.clone(), class_name.clone());
+            }
+
+            let external_types = class
+                .class
+                .external_types
+                .iter()
+                .chain(class.functions.iter().flat_map(|f| &f.external_types))
+                .chain(class.properties.iter().flat_map(|p| &p.external_types))
+                .chain(class.types.iter().flat_map(|t| &t.external_types))
+                .chain(class.events.iter().flat_map(|e| &e.external_types));
+
+            for external in external_types {
+                targets
+                    .externals
+                    .insert(external.name.to_string(), external.url.to_string());
+            }
+        }
+
+        targets.add_inherited_members(classes);
+
+        targets
+    }
+
+    /// Links to members a class inherits with `@extends` lead to the class itself, since inherited
+    /// members are listed with it
+    fn add_inherited_members(&mut self, classes: &[OutputClass]) {
+        for class in classes {
+            let mut inherited = BTreeSet::new();
+            let mut visited = vec![class.class.name.as_str()];
+            let mut extends = class.class.extends;
+
+            while let Some(parent) = extends.map(|parent| parent.as_str()) {
+                if visited.contains(&parent) {
+                    break;
+                }
+
+                visited.push(parent);
+
+                if let Some(members) = self.classes.get(parent) {
+                    inherited.extend(members.iter().cloned());
+                }
+
+                extends = classes
+                    .iter()
+                    .find(|candidate| candidate.class.name == parent)
+                    .and_then(|parent| parent.class.extends);
+            }
+
+            if let Some(members) = self.classes.get_mut(&class.class.name) {
+                members.extend(inherited);
+            }
+        }
+    }
+
+    /// Finds where a reference like `Class`, `Class.member` or `Class:method` links to. Built-in
+    /// types resolve to `None`, since they exist but we don't know where their docs are.
+    fn resolve(&self, reference: &str) -> Result<Option<String>, String> {
+        if self.classes.contains_key(reference) {
+            return Ok(Some(reference.to_owned()));
+        }
+
+        if let Some(url) = self.externals.get(reference) {
+            return Ok(Some(url.clone()));
+        }
+
+        if let Some(class_name) = self.types.get(reference) {
+            return Ok(Some(format!("{}#{}", class_name, reference)));
+        }
+
+        if self.builtins.contains(reference) || reference.starts_with("Enum.") {
+            return Ok(None);
+        }
+
+        match reference.rfind(['.', ':']) {
+            Some(index) => {
+                let (class_name, member) = (&reference[..index], &reference[index + 1..]);
+
+                match self.classes.get(class_name) {
+                    Some(members) if members.contains(member) => {
+                        Ok(Some(format!("{}#{}", class_name, member)))
+                    }
+                    Some(members) => Err(with_suggestion(
+                        format!("Class \"{}\" has no member \"{}\"", class_name, member),
+                        suggestion(member, members.iter().map(String::as_str))
+                            .map(|member| format!("{}{}", &reference[..=index], member)),
+                    )),
+                    None if self.builtins.contains(class_name) => Ok(None),
+                    None => Err(with_suggestion(
+                        format!("Could not resolve link to \"{}\"", reference),
+                        suggestion(class_name, self.classes.keys().map(String::as_str))
+                            .map(|class_name| format!("{}{}", class_name, &reference[index..])),
+                    )),
+                }
+            }
+            None => Err(with_suggestion(
+                format!("Could not resolve link to \"{}\"", reference),
+                suggestion(
+                    reference,
+                    self.classes
+                        .keys()
+                        .chain(self.types.keys())
+                        .chain(self.externals.keys())
+                        .map(String::as_str),
+                )
+                .map(str::to_owned),
+            )),
+        }
+    }
+}
+
+fn with_suggestion(text: String, suggestion: Option<String>) -> String {
+    did_you_mean(text, suggestion.as_deref())
+}
+
+/// Whether the text between the brackets looks like a reference to something, like `Class`,
+/// `Class.member` or `Class:method`, as opposed to any other text in brackets
+fn is_reference(text: &str) -> bool {
+    let is_name = |name: &str| {
+        name.chars()
+            .next()
+            .is_some_and(|char| char.is_alphabetic() || char == '_')
+            && name
+                .chars()
+                .all(|char| char.is_alphanumeric() || char == '_')
+    };
+
+    let (path, method) = match text.rsplit_once(':') {
+        Some((path, method)) => (path, Some(method)),
+        None => (text, None),
+    };
+
+    path.split('.').all(is_name) && method.is_none_or(is_name)
+}
+
+/// Whether text comes right after a list item's marker, like `- ` or `1. `, where `[x]` and `[ ]`
+/// are task list checkboxes
+fn is_list_item_start(text: &str) -> bool {
+    let text = text.trim();
+
+    matches!(text, "-" | "*" | "+")
+        || text
+            .strip_suffix('.')
+            .is_some_and(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// A `[Reference]` or `` [`Reference`] `` in a description
+struct Reference<'a> {
+    span: Span<'a>,
+    name: &'a str,
+    /// Whether the reference is written in backticks, which always makes it a link
+    backticked: bool,
+}
+
+impl Reference<'_> {
+    /// Whether the reference is clearly meant as a link, rather than text that happens to be in
+    /// brackets, like `[Note]`. Only these are reported when they don't resolve.
+    fn is_explicit(&self) -> bool {
+        self.backticked || self.name.contains(['.', ':'])
+    }
+}
+
+/// Finds the `[Reference]` and `` [`Reference`] `` style references in a line, skipping inline
+/// code, regular markdown links like `[text](url)` and `[text][label]`, indexing like `t[i]`, and
+/// task list checkboxes
+fn find_references<'a>(line: Span<'a>) -> Vec<Reference<'a>> {
+    let text = line.as_str();
+    let mut references = Vec::new();
+    let mut index = 0;
+
+    while index < text.len() {
+        let rest = &text[index..];
+
+        if let Some(code) = rest.strip_prefix('`') {
+            match code.find('`') {
+                Some(end) => index += end + 2,
+                None => break,
+            }
+
+            continue;
+        }
+
+        if !rest.starts_with('[') {
+            index += rest.chars().next().map_or(1, char::len_utf8);
+            continue;
+        }
+
+        let end = match rest.find(']') {
+            Some(end) => end,
+            None => break,
+        };
+
+        let label = &rest[1..end];
+        let backticked = label.len() > 1 && label.starts_with('`') && label.ends_with('`');
+        let name = match backticked {
+            true => &label[1..label.len() - 1],
+            false => label,
+        };
+
+        let after = rest[end + 1..].chars().next();
+        let before = text[..index].chars().last();
+        let is_checkbox = matches!(label, "x" | "X" | " ") && is_list_item_start(&text[..index]);
+
+        if !matches!(after, Some(':' | '(' | '{' | '['))
+            && !matches!(before, Some(']' | '!'))
+            && !before.is_some_and(|char| char.is_alphanumeric() || char == '_')
+            && !is_checkbox
+            && is_reference(name)
+        {
+            references.push(Reference {
+                span: line.slice(index, end + 1),
+                name,
+                backticked,
+            });
+        }
+
+        index += end + 1;
+    }
+
+    references
+}
+
+/// Finds the part of a tag that holds its description
+fn desc_span<'a>(tag_source: Span<'a>, desc: &str) -> Option<Span<'a>> {
+    if desc.is_empty() {
+        return None;
+    }
+
+    tag_source
+        .as_str()
+        .rfind(desc)
+        .map(|start| tag_source.slice(start, desc.len()))
+}
+
+/// Resolves the references in a description, collecting their links
+struct LinkResolver<'t> {
+    targets: &'t LinkTargets,
+    /// Whether to report references that don't resolve
+    check: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl LinkResolver<'_> {
+    fn resolve<'a>(
+        &mut self,
+        lines: impl IntoIterator<Item = Span<'a>>,
+        links: &mut BTreeMap<String, String>,
+    ) {
+        let mut in_code_block = false;
+
+        for line in lines {
+            let trimmed = line.trim_start();
+
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+
+            if in_code_block {
+                continue;
+            }
+
+            for reference in find_references(line) {
+                match self.targets.resolve(reference.name) {
+                    Ok(Some(link)) => {
+                        links.insert(reference.name.to_owned(), link);
+                    }
+                    Ok(None) => {}
+                    Err(text) if self.check && reference.is_explicit() => self
+                        .diagnostics
+                        .push(reference.span.diagnostic(text).into_warning()),
+                    Err(_) => {}
+                }
+            }
+        }
+    }
+}
+
+/// Checks the references in every description, filling in the `links` of each entry. When
+/// `check` is set, qualified or backticked references that don't lead anywhere are reported as
+/// warnings. Plain `[Name]`s are only links if they name something, since they might just be text
+/// in brackets.
+pub fn resolve_links(
+    classes: &mut [OutputClass],
+    known_types: &[String],
+    check: bool,
+) -> Vec<Diagnostic> {
+    let targets = LinkTargets::new(classes, known_types);
+    let mut resolver = LinkResolver {
+        targets: &targets,
+        check,
+        diagnostics: Vec::new(),
+    };
+
+    for class in classes {
+        let entry = &mut class.class;
+        resolver.resolve(entry.desc_spans.iter().copied(), &mut entry.links);
+
+        for function in &mut class.functions {
+            let mut lines = function.desc_spans.clone();
+
+            lines.extend(
+                function
+                    .signatures
+                    .iter()
+                    .skip(1)
+                    .flat_map(|signature| signature.desc_spans.iter().copied()),
+            );
+            lines.extend(
+                function
+                    .all_params()
+                    .filter_map(|param| desc_span(param.tag_source?, &param.desc)),
+            );
+            lines.extend(
+                function
+                    .all_returns()
+                    .filter_map(|ret| desc_span(ret.tag_source?, &ret.desc)),
+            );
+            lines.extend(function.errors.iter().map(|error| error.desc));
+
+            resolver.resolve(lines, &mut function.links);
+        }
+
+        for property in &mut class.properties {
+            resolver.resolve(property.desc_spans.iter().copied(), &mut property.links);
+        }
+
+        for event in &mut class.events {
+            let mut lines = event.desc_spans.clone();
+
+            lines.extend(
+                event
+                    .params
+                    .iter()
+                    .filter_map(|param| desc_span(param.tag_source?, &param.desc)),
+            );
+
+            resolver.resolve(lines, &mut event.links);
+        }
+
+        for type_entry in &mut class.types {
+            let mut lines = type_entry.desc_spans.clone();
+
+            lines.extend(
+                type_entry
+                    .fields
+                    .iter()
+                    .filter_map(|field| desc_span(field.tag_source, &field.desc)),
+            );
+
+            resolver.resolve(lines, &mut type_entry.links);
+        }
+    }
+
+    resolver.diagnostics
+}
@@ -0,0 +1,303 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{diagnostic_at, did_you_mean, roblox_classes::ROBLOX_CLASSES, suggestion};
+use crate::{
+    diagnostic::Diagnostic, doc_comment::DocComment, span::Span, type_ast::TypeAst, OutputClass,
+};
+
+/// Types that are built into Luau
+static LUAU_TYPES: &[&str] = &[
+    "any", "boolean", "buffer", "function", "never", "nil", "number", "string", "table", "thread",
+    "unknown", "userdata", "vector",
+];
+
+/// Roblox data types. Instance classes are in `ROBLOX_CLASSES`, and anything else can be passed
+/// with `--known-type`.
+static ROBLOX_TYPES: &[&str] = &[
+    "Axes",
+    "BrickColor",
+    "CFrame",
+    "CatalogSearchParams",
+    "Color3",
+    "ColorSequence",
+    "ColorSequenceKeypoint",
+    "Content",
+    "DateTime",
+    "DockWidgetPluginGuiInfo",
+    "Enum",
+    "EnumItem",
+    "Enums",
+    "Faces",
+    "FloatCurveKey",
+    "Font",
+    "Instance",
+    "NumberRange",
+    "NumberSequence",
+    "NumberSequenceKeypoint",
+    "OverlapParams",
+    "PathWaypoint",
+    "PhysicalProperties",
+    "Random",
+    "RBXScriptConnection",
+    "RBXScriptSignal",
+    "Ray",
+    "RaycastParams",
+    "RaycastResult",
+    "Rect",
+    "Region3",
+    "Region3int16",
+    "SharedTable",
+    "TweenInfo",
+    "UDim",
+    "UDim2",
+    "Vector2",
+    "Vector2int16",
+    "Vector3",
+    "Vector3int16",
+];
+
+/// The built-in types along with the types passed with `--known-type`
+pub(super) fn builtin_types(known_types: &[String]) -> impl Iterator<Item = &str> {
+    LUAU_TYPES
+        .iter()
+        .chain(ROBLOX_TYPES)
+        .chain(ROBLOX_CLASSES)
+        .copied()
+        .chain(known_types.iter().map(String::as_str))
+}
+
+/// Checks that every name used in a type refers to something that exists
+struct Resolver<'c> {
+    /// Names that resolve from anywhere
+    global: BTreeSet<&'c str>,
+    /// The unqualified names of the types within each class
+    class_types: BTreeMap<&'c str, BTreeSet<&'c str>>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'c> Resolver<'c> {
+    fn is_known(&self, name: &str, class_name: &str, generics: &[String]) -> bool {
+        self.global.contains(name)
+            || self.class_types[class_name].contains(name)
+            || name.starts_with("Enum.")
+            || generics.iter().any(|generic| generic == name)
+    }
+
+    /// Checks the names in a type. `generics` are the type variables declared by the function or
+    /// type the type belongs to.
+    fn check(
+        &mut self,
+        class_name: &str,
+        generics: &[String],
+        lua_type: &str,
+        type_ast: Option<&TypeAst>,
+        tag_source: Option<Span>,
+        doc_comment: &DocComment,
+    ) {
+        let mut names = Vec::new();
+        if let Some(type_ast) = type_ast {
+            referenced_names(type_ast, &mut names);
+        }
+
+        for name in names {
+            if self.is_known(name, class_name, generics) {
+                continue;
+            }
+
+            let candidates = self
+                .global
+                .iter()
+                .chain(self.class_types[class_name].iter())
+                .copied()
+                .chain(generics.iter().map(String::as_str));
+
+            let text = did_you_mean(
+                format!("Unknown type \"{}\"", name),
+                suggestion(name, candidates),
+            );
+
+            let tag_source = tag_source.map(|span| name_span(span, lua_type, name));
+
+            self.diagnostics
+                .push(diagnostic_at(text, tag_source, doc_comment).into_warning());
+        }
+    }
+}
+
+fn referenced_names<'t>(type_ast: &'t TypeAst, names: &mut Vec<&'t str>) {
+    match type_ast {
+        TypeAst::Reference { name } => names.push(name),
+        TypeAst::Generic { name, generics } => {
+            names.push(name);
+
+            for generic in generics {
+                referenced_names(generic, names);
+            }
+        }
+        TypeAst::Optional { inner } | TypeAst::Variadic { inner } => referenced_names(inner, names),
+        TypeAst::Array { element } => referenced_names(element, names),
+        TypeAst::Union { types } | TypeAst::Intersection { types } | TypeAst::Tuple { types } => {
+            for inner in types {
+                referenced_names(inner, names);
+            }
+        }
+        TypeAst::Function { params, returns } => {
+            for param in params {
+                referenced_names(&param.lua_type, names);
+            }
+
+            for inner in returns {
+                referenced_names(inner, names);
+            }
+        }
+        TypeAst::Table { fields } => {
+            for field in fields {
+                if let Some(indexer) = &field.indexer {
+                    referenced_names(indexer, names);
+                }
+
+                referenced_names(&field.value, names);
+            }
+        }
+        TypeAst::Literal { .. } | TypeAst::Typeof { .. } | TypeAst::GenericPack { .. } => {}
+    }
+}
+
+/// Narrows a tag down to where the name appears within its type, so the warning points at the
+/// name itself
+fn name_span<'a>(tag_source: Span<'a>, lua_type: &str, name: &str) -> Span<'a> {
+    let text = tag_source.as_str();
+    let is_identifier = |char: char| char.is_alphanumeric() || char == '_';
+
+    text.find(lua_type.trim())
+        .and_then(|type_start| {
+            text[type_start..]
+                .match_indices(name)
+                .map(|(index, _)| type_start + index)
+                .find(|&start| {
+                    !text[..start].ends_with(is_identifier)
+                        && !text[start + name.len()..].starts_with(is_identifier)
+                })
+        })
+        .map(|start| tag_source.slice(start, name.len()))
+        .unwrap_or(tag_source)
+}
+
+/// Reports every name used in a type that isn't a class, a type, an `@external` type, a built-in
+/// type, or one of the `known_types`
+pub fn resolve_types(classes: &[OutputClass], known_types: &[String]) -> Vec<Diagnostic> {
+    let mut global: BTreeSet<&str> = builtin_types(known_types).collect();
+
+    let mut qualified_names = Vec::new();
+    let mut class_types = BTreeMap::new();
+
+    for class in classes {
+        global.insert(&class.class.name);
+        global.extend(
+            class
+                .class
+                .external_types
+                .iter()
+                .chain(class.functions.iter().flat_map(|f| &f.external_types))
+                .chain(class.properties.iter().flat_map(|p| &p.external_types))
+                .chain(class.types.iter().flat_map(|t| &t.external_types))
+                .chain(class.events.iter().flat_map(|e| &e.external_types))
+                .map(|external| external.name.as_str()),
+        );
+
+        for type_entry in &class.types {
+            qualified_names.push(format!("{}.{}", class.class.name, type_entry.name));
+        }
+
+        class_types.insert(
+            class.class.name.as_str(),
+            class.types.iter().map(|t| t.name.as_str()).collect(),
+        );
+    }
+
+    global.extend(qualified_names.iter().map(String::as_str));
+
+    let mut resolver = Resolver {
+        global,
+        class_types,
+        diagnostics: Vec::new(),
+    };
+
+    for class in classes {
+        let class_name = class.class.name.as_str();
+
+        for function in &class.functions {
+            for param in function.all_params() {
+                resolver.check(
+                    class_name,
+                    &function.generics,
+                    &param.lua_type,
+                    param.type_ast.as_ref(),
+                    param.tag_source,
+                    function.source,
+                );
+            }
+
+            for ret in function.all_returns() {
+                resolver.check(
+                    class_name,
+                    &function.generics,
+                    &ret.lua_type,
+                    ret.type_ast.as_ref(),
+                    ret.tag_source,
+                    function.source,
+                );
+            }
+        }
+
+        for property in &class.properties {
+            resolver.check(
+                class_name,
+                &[],
+                &property.lua_type,
+                property.type_ast.as_ref(),
+                property.tag_source,
+                property.source,
+            );
+        }
+
+        for event in &class.events {
+            for param in &event.params {
+                resolver.check(
+                    class_name,
+                    &[],
+                    &param.lua_type,
+                    param.type_ast.as_ref(),
+                    param.tag_source,
+                    event.source,
+                );
+            }
+        }
+
+        for type_entry in &class.types {
+            if let Some(lua_type) = &type_entry.lua_type {
+                resolver.check(
+                    class_name,
+                    &type_entry.generics,
+                    lua_type,
+                    type_entry.type_ast.as_ref(),
+                    type_entry.tag_source,
+                    type_entry.source,
+                );
+            }
+
+            for field in &type_entry.fields {
+                resolver.check(
+                    class_name,
+                    &type_entry.generics,
+                    &field.lua_type,
+                    field.type_ast.as_ref(),
+                    Some(field.tag_source),
+                    type_entry.source,
+                );
+            }
+        }
+    }
+
+    resolver.diagnostics
+}
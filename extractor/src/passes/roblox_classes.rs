@@ -0,0 +1,539 @@
+/// The instance classes in the Roblox API dump, which the docusaurus plugin also uses to link to
+/// Roblox's docs in `generateRobloxTypes.js`. This is a snapshot, so classes Roblox adds later can
+/// be passed with `--known-types-file`. To update it, list the classes in the current dump with
+/// `curl -s https://raw.githubusercontent.com/CloneTrooper1019/Roblox-Client-Watch/roblox/API-Dump.json | jq -r '.Classes[].Name' | sort`
+pub(super) static ROBLOX_CLASSES: &[&str] = &[
+    "Accessory",
+    "AccessoryDescription",
+    "Accoutrement",
+    "Actor",
+    "AdGui",
+    "AdPortal",
+    "AdService",
+    "AdvancedDragger",
+    "AirController",
+    "AlignOrientation",
+    "AlignPosition",
+    "AnalyticsService",
+    "AngularVelocity",
+    "Animation",
+    "AnimationClip",
+    "AnimationClipProvider",
+    "AnimationConstraint",
+    "AnimationController",
+    "AnimationFromVideoCreatorService",
+    "AnimationRigData",
+    "AnimationTrack",
+    "Animator",
+    "ArcHandles",
+    "Atmosphere",
+    "Attachment",
+    "AudioAnalyzer",
+    "AudioChorus",
+    "AudioCompressor",
+    "AudioDeviceInput",
+    "AudioDeviceOutput",
+    "AudioDistortion",
+    "AudioEcho",
+    "AudioEmitter",
+    "AudioEqualizer",
+    "AudioFader",
+    "AudioFlanger",
+    "AudioListener",
+    "AudioPitchShifter",
+    "AudioPlayer",
+    "AudioReverb",
+    "AudioSearchParams",
+    "AvatarEditorService",
+    "Backpack",
+    "BackpackItem",
+    "BadgeService",
+    "BallSocketConstraint",
+    "BasePart",
+    "BasePlayerGui",
+    "BaseScript",
+    "BaseWrap",
+    "Beam",
+    "BevelMesh",
+    "BillboardGui",
+    "BinaryStringValue",
+    "BindableEvent",
+    "BindableFunction",
+    "BlockMesh",
+    "BloomEffect",
+    "BlurEffect",
+    "BodyAngularVelocity",
+    "BodyColors",
+    "BodyForce",
+    "BodyGyro",
+    "BodyMover",
+    "BodyPartDescription",
+    "BodyPosition",
+    "BodyThrust",
+    "BodyVelocity",
+    "Bone",
+    "BoolValue",
+    "BoxHandleAdornment",
+    "Breakpoint",
+    "BrickColorValue",
+    "BubbleChatConfiguration",
+    "BubbleChatMessageProperties",
+    "BuoyancySensor",
+    "CacheableContentProvider",
+    "CalloutService",
+    "Camera",
+    "CanvasGroup",
+    "CatalogPages",
+    "CFrameValue",
+    "ChangeHistoryService",
+    "ChannelSelectorSoundEffect",
+    "ChannelTabsConfiguration",
+    "CharacterAppearance",
+    "CharacterMesh",
+    "Chat",
+    "ChatInputBarConfiguration",
+    "ChatWindowConfiguration",
+    "ChorusSoundEffect",
+    "ClickDetector",
+    "ClientReplicator",
+    "Clothing",
+    "Clouds",
+    "ClusterPacketCache",
+    "CollectionService",
+    "Color3Value",
+    "ColorCorrectionEffect",
+    "ColorGradingEffect",
+    "CompressorSoundEffect",
+    "ConeHandleAdornment",
+    "Configuration",
+    "Constraint",
+    "ContentProvider",
+    "ContextActionService",
+    "Controller",
+    "ControllerBase",
+    "ControllerManager",
+    "ControllerPartSensor",
+    "ControllerSensor",
+    "CoreGui",
+    "CornerWedgePart",
+    "CSGDictionaryService",
+    "CurveAnimation",
+    "CustomEvent",
+    "CustomEventReceiver",
+    "CylinderHandleAdornment",
+    "CylinderMesh",
+    "CylindricalConstraint",
+    "DataModel",
+    "DataModelMesh",
+    "DataStore",
+    "DataStoreInfo",
+    "DataStoreKey",
+    "DataStoreKeyInfo",
+    "DataStoreKeyPages",
+    "DataStoreListingPages",
+    "DataStoreObjectVersionInfo",
+    "DataStoreOptions",
+    "DataStorePages",
+    "DataStoreService",
+    "DataStoreSetOptions",
+    "DataStoreVersionPages",
+    "Debris",
+    "DebuggerWatch",
+    "Decal",
+    "DepthOfFieldEffect",
+    "Dialog",
+    "DialogChoice",
+    "DistortionSoundEffect",
+    "DoubleConstrainedValue",
+    "DragDetector",
+    "Dragger",
+    "DraggerService",
+    "DynamicRotate",
+    "EchoSoundEffect",
+    "EditableImage",
+    "EditableMesh",
+    "EqualizerSoundEffect",
+    "EulerRotationCurve",
+    "ExperienceInviteOptions",
+    "Explosion",
+    "FaceControls",
+    "FaceInstance",
+    "Feature",
+    "File",
+    "FileMesh",
+    "Fire",
+    "Flag",
+    "FlagStand",
+    "FlagStandService",
+    "FlangeSoundEffect",
+    "FloatCurve",
+    "FloorWire",
+    "FluidForceSensor",
+    "Folder",
+    "ForceField",
+    "FormFactorPart",
+    "Frame",
+    "FriendPages",
+    "FriendService",
+    "FunctionalTest",
+    "GamePassService",
+    "GameSettings",
+    "GenericSettings",
+    "Geometry",
+    "GetTextBoundsParams",
+    "GlobalDataStore",
+    "GlobalSettings",
+    "Glue",
+    "GroupService",
+    "GuiBase",
+    "GuiBase2d",
+    "GuiBase3d",
+    "GuiButton",
+    "GuidRegistryService",
+    "GuiLabel",
+    "GuiMain",
+    "GuiObject",
+    "GuiService",
+    "HandleAdornment",
+    "Handles",
+    "HandlesBase",
+    "HapticService",
+    "Hat",
+    "HeightmapImporterService",
+    "HiddenSurfaceRemovalAsset",
+    "Highlight",
+    "HingeConstraint",
+    "Hint",
+    "Hole",
+    "HttpRbxApiService",
+    "HttpService",
+    "Humanoid",
+    "HumanoidController",
+    "HumanoidDescription",
+    "HumanoidRigDescription",
+    "IKControl",
+    "ImageButton",
+    "ImageHandleAdornment",
+    "ImageLabel",
+    "ImporterAnimationSettings",
+    "ImporterBaseSettings",
+    "ImporterFacsSettings",
+    "ImporterGroupSettings",
+    "ImporterJointSettings",
+    "ImporterMaterialSettings",
+    "ImporterMeshSettings",
+    "ImporterRootSettings",
+    "IncrementalPatchBuilder",
+    "InputObject",
+    "InsertService",
+    "IntConstrainedValue",
+    "IntValue",
+    "InventoryPages",
+    "JointInstance",
+    "JointsService",
+    "KeyboardService",
+    "Keyframe",
+    "KeyframeMarker",
+    "KeyframeSequence",
+    "KeyframeSequenceProvider",
+    "LanguageService",
+    "LayerCollector",
+    "Light",
+    "Lighting",
+    "LinearVelocity",
+    "LineForce",
+    "LineHandleAdornment",
+    "LocalizationService",
+    "LocalizationTable",
+    "LocalScript",
+    "LodDataEntity",
+    "LodDataService",
+    "LogService",
+    "LuaSourceContainer",
+    "LuaWebService",
+    "ManualGlue",
+    "ManualSurfaceJointInstance",
+    "ManualWeld",
+    "MarkerCurve",
+    "MarketplaceService",
+    "MaterialService",
+    "MaterialVariant",
+    "MemoryStoreHashMap",
+    "MemoryStoreHashMapPages",
+    "MemoryStoreQueue",
+    "MemoryStoreService",
+    "MemoryStoreSortedMap",
+    "MemStorageConnection",
+    "MemStorageService",
+    "MeshContentProvider",
+    "MeshPart",
+    "Message",
+    "MessagingService",
+    "MetaBreakpoint",
+    "Model",
+    "ModuleScript",
+    "Motor",
+    "Motor6D",
+    "MotorFeature",
+    "Mouse",
+    "MouseService",
+    "MultipleDocumentInterfaceInstance",
+    "NegateOperation",
+    "NetworkClient",
+    "NetworkMarker",
+    "NetworkPeer",
+    "NetworkReplicator",
+    "NetworkServer",
+    "NetworkSettings",
+    "NoCollisionConstraint",
+    "Noise",
+    "NotificationService",
+    "NumberPose",
+    "NumberValue",
+    "Object",
+    "ObjectValue",
+    "OrderedDataStore",
+    "OutfitPages",
+    "PackageLink",
+    "Pages",
+    "Pants",
+    "ParabolaAdornment",
+    "Part",
+    "PartAdornment",
+    "ParticleEmitter",
+    "PartOperation",
+    "PartOperationAsset",
+    "Path",
+    "PathfindingLink",
+    "PathfindingModifier",
+    "PathfindingService",
+    "PermissionsService",
+    "PhysicsService",
+    "PhysicsSettings",
+    "PitchShiftSoundEffect",
+    "Plane",
+    "PlaneConstraint",
+    "Platform",
+    "Player",
+    "PlayerGui",
+    "PlayerMouse",
+    "Players",
+    "PlayerScripts",
+    "Plugin",
+    "PluginAction",
+    "PluginDebugService",
+    "PluginDragEvent",
+    "PluginGui",
+    "PluginGuiService",
+    "PluginManager",
+    "PluginManagerInterface",
+    "PluginMenu",
+    "PluginMouse",
+    "PluginToolbar",
+    "PluginToolbarButton",
+    "PointLight",
+    "PointsService",
+    "PolicyService",
+    "Pose",
+    "PoseBase",
+    "PostEffect",
+    "PrismaticConstraint",
+    "ProcessInstancePhysicsService",
+    "ProximityPrompt",
+    "ProximityPromptService",
+    "PublishService",
+    "PVAdornment",
+    "PVInstance",
+    "QWidgetPluginGui",
+    "RayValue",
+    "ReflectionMetadata",
+    "ReflectionMetadataCallbacks",
+    "ReflectionMetadataClass",
+    "ReflectionMetadataClasses",
+    "ReflectionMetadataEnum",
+    "ReflectionMetadataEnumItem",
+    "ReflectionMetadataEnums",
+    "ReflectionMetadataEvents",
+    "ReflectionMetadataFunctions",
+    "ReflectionMetadataItem",
+    "ReflectionMetadataMember",
+    "ReflectionMetadataProperties",
+    "ReflectionMetadataYieldFunctions",
+    "RemoteEvent",
+    "RemoteFunction",
+    "RenderingTest",
+    "RenderSettings",
+    "ReplicatedFirst",
+    "ReplicatedStorage",
+    "ReverbSoundEffect",
+    "RigidConstraint",
+    "RocketPropulsion",
+    "RodConstraint",
+    "RopeConstraint",
+    "Rotate",
+    "RotateP",
+    "RotateV",
+    "RotationCurve",
+    "RTAnimationTracker",
+    "RunningAverageItemDouble",
+    "RunningAverageItemInt",
+    "RunningAverageTimeIntervalItem",
+    "RunService",
+    "RuntimeScriptService",
+    "ScreenGui",
+    "ScreenshotHud",
+    "Script",
+    "ScriptContext",
+    "ScriptDebugger",
+    "ScriptDocument",
+    "ScriptEditorService",
+    "ScriptService",
+    "ScrollingFrame",
+    "Seat",
+    "Selection",
+    "SelectionBox",
+    "SelectionLasso",
+    "SelectionPartLasso",
+    "SelectionPointLasso",
+    "SelectionSphere",
+    "SensorBase",
+    "ServerScriptService",
+    "ServerStorage",
+    "ServiceProvider",
+    "Shirt",
+    "ShirtGraphic",
+    "SkateboardController",
+    "SkateboardPlatform",
+    "Skin",
+    "Sky",
+    "SlidingBallConstraint",
+    "Smoke",
+    "Snap",
+    "SocialService",
+    "SolidModelContentProvider",
+    "Sound",
+    "SoundEffect",
+    "SoundGroup",
+    "SoundService",
+    "Sparkles",
+    "SpawnLocation",
+    "SpecialMesh",
+    "SphereHandleAdornment",
+    "SpotLight",
+    "SpringConstraint",
+    "StandalonePluginScripts",
+    "StarterCharacterScripts",
+    "StarterGear",
+    "StarterGui",
+    "StarterPack",
+    "StarterPlayer",
+    "StarterPlayerScripts",
+    "Stats",
+    "StatsItem",
+    "Status",
+    "StringValue",
+    "Studio",
+    "StudioService",
+    "StyleBase",
+    "StyleDerive",
+    "StyleLink",
+    "StyleRule",
+    "StyleSheet",
+    "SunRaysEffect",
+    "SurfaceAppearance",
+    "SurfaceGui",
+    "SurfaceGuiBase",
+    "SurfaceLight",
+    "SurfaceSelection",
+    "SurfaceTexture",
+    "SwimController",
+    "TaskScheduler",
+    "Team",
+    "TeamCreateService",
+    "Teams",
+    "TeleportAsyncResult",
+    "TeleportOptions",
+    "TeleportService",
+    "Terrain",
+    "TerrainDetail",
+    "TerrainRegion",
+    "TestService",
+    "TextBox",
+    "TextButton",
+    "TextChannel",
+    "TextChatCommand",
+    "TextChatConfigurations",
+    "TextChatMessage",
+    "TextChatMessageProperties",
+    "TextChatService",
+    "TextFilterResult",
+    "TextLabel",
+    "TextService",
+    "TextSource",
+    "Texture",
+    "Tool",
+    "Torque",
+    "TorsionSpringConstraint",
+    "TouchInputService",
+    "TouchTransmitter",
+    "TrackerStreamAnimation",
+    "Trail",
+    "Translator",
+    "TremoloSoundEffect",
+    "TriangleMeshPart",
+    "TrussPart",
+    "Tween",
+    "TweenBase",
+    "TweenService",
+    "UGCValidationService",
+    "UIAspectRatioConstraint",
+    "UIBase",
+    "UIComponent",
+    "UIConstraint",
+    "UICorner",
+    "UIDragDetector",
+    "UIFlexItem",
+    "UIGradient",
+    "UIGridLayout",
+    "UIGridStyleLayout",
+    "UILayout",
+    "UIListLayout",
+    "UIPadding",
+    "UIPageLayout",
+    "UIScale",
+    "UISizeConstraint",
+    "UIStroke",
+    "UITableLayout",
+    "UITextSizeConstraint",
+    "UnionOperation",
+    "UniversalConstraint",
+    "UnreliableRemoteEvent",
+    "UserGameSettings",
+    "UserInputService",
+    "UserService",
+    "ValueBase",
+    "Vector3Curve",
+    "Vector3Value",
+    "VectorForce",
+    "VehicleController",
+    "VehicleSeat",
+    "VelocityMotor",
+    "VideoCaptureService",
+    "VideoFrame",
+    "ViewportFrame",
+    "VirtualInputManager",
+    "VirtualUser",
+    "VisibilityCheckDispatcher",
+    "Visit",
+    "VoiceChatService",
+    "VRService",
+    "WedgePart",
+    "Weld",
+    "WeldConstraint",
+    "WireframeHandleAdornment",
+    "Workspace",
+    "WorldModel",
+    "WorldRoot",
+    "WrapLayer",
+    "WrapTarget",
+];
@@ -0,0 +1,138 @@
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context};
+use fs_err as fs;
+use serde_json::Value;
+
+/// The instances in the DataModel that a Rojo project syncs files into, used to show how each
+/// class is required
+#[derive(Debug, Default)]
+pub struct RojoProject {
+    /// Every `$path` in the project, canonicalized, along with the path of the instance it's synced
+    /// to
+    mounts: Vec<(PathBuf, Vec<String>)>,
+}
+
+impl RojoProject {
+    /// Reads a `*.project.json` file, along with any projects nested in it
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let mut project = Self::default();
+        project.add_project(path, None)?;
+
+        Ok(project)
+    }
+
+    /// Adds a project file's tree. A nested project takes the place of the instance it's synced
+    /// to, while the root of the top-level project is named after the project, unless it's the
+    /// DataModel itself.
+    fn add_project(&mut self, path: &Path, instance: Option<Vec<String>>) -> anyhow::Result<()> {
+        let contents = fs::read_to_string(path)?;
+        let project: Value = serde_json::from_str(&contents)
+            .with_context(|| format!("{} is not a valid Rojo project", path.display()))?;
+
+        let Some(tree) = project.get("tree") else {
+            bail!(
+                "{} is not a valid Rojo project: it has no tree",
+                path.display()
+            );
+        };
+
+        let instance = match instance {
+            Some(instance) => instance,
+            None if tree.get("$className").and_then(Value::as_str) == Some("DataModel") => {
+                Vec::new()
+            }
+            None => project
+                .get("name")
+                .and_then(Value::as_str)
+                .map(|name| vec![name.to_owned()])
+                .unwrap_or_default(),
+        };
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        self.add_node(tree, base, instance)
+    }
+
+    fn add_node(&mut self, node: &Value, base: &Path, instance: Vec<String>) -> anyhow::Result<()> {
+        // `$path` is either a path, or `{ "optional": path }` for one that might not exist
+        let path = node.get("$path").and_then(|path| match path {
+            Value::String(path) => Some(path.as_str()),
+            path => path.get("optional").and_then(Value::as_str),
+        });
+
+        if let Some(path) = path {
+            let path = base.join(path);
+            let nested_project = path.join("default.project.json");
+
+            if path.to_string_lossy().ends_with(".project.json") {
+                self.add_project(&path, Some(instance.clone()))?;
+            } else if nested_project.is_file() {
+                self.add_project(&nested_project, Some(instance.clone()))?;
+            } else if let Ok(path) = fs::canonicalize(&path) {
+                self.mounts.push((path, instance.clone()));
+            }
+        }
+
+        if let Some(children) = node.as_object() {
+            for (name, child) in children {
+                if name.starts_with('$') {
+                    continue;
+                }
+
+                let mut child_instance = instance.clone();
+                child_instance.push(name.clone());
+
+                self.add_node(child, base, child_instance)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the DataModel path a file is synced to, like `ReplicatedStorage.Packages.Fabric`.
+    /// `init` files are synced to the folder they're in, and `.server` and `.client` files are
+    /// named without that suffix.
+    pub fn data_model_path(&self, file: &Path) -> Option<String> {
+        let file = fs::canonicalize(file).ok()?;
+
+        let (mount, instance) = self
+            .mounts
+            .iter()
+            .filter(|(mount, _)| file.starts_with(mount))
+            .max_by_key(|(mount, _)| mount.components().count())?;
+
+        let mut path = instance.clone();
+        let rest: Vec<String> = file
+            .strip_prefix(mount)
+            .ok()?
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+                _ => None,
+            })
+            .collect();
+
+        if let Some((file_name, folders)) = rest.split_last() {
+            path.extend(folders.iter().cloned());
+
+            match instance_name(file_name) {
+                "init" => {}
+                name => path.push(name.to_owned()),
+            }
+        }
+
+        (!path.is_empty()).then(|| path.join("."))
+    }
+}
+
+/// The name of the script instance a file becomes
+fn instance_name(file_name: &str) -> &str {
+    let name = file_name
+        .strip_suffix(".luau")
+        .or_else(|| file_name.strip_suffix(".lua"))
+        .unwrap_or(file_name);
+
+    name.strip_suffix(".server")
+        .or_else(|| name.strip_suffix(".client"))
+        .unwrap_or(name)
+}
@@ -0,0 +1,268 @@
+use crate::{
+    doc_entry::{FunctionDocEntry, FunctionParam, FunctionSignature, FunctionType},
+    tags::DeprecatedTag,
+    type_ast::TypeAst,
+    OutputClass,
+};
+
+/// The type of an argument, in the terms selene checks
+#[derive(Debug, PartialEq)]
+enum ArgType {
+    /// A type selene knows, like `number` or `bool`
+    Builtin(&'static str),
+    /// Any other type, which selene shows by name but doesn't check
+    Display(String),
+    /// One of a set of strings, from a union of string literals
+    Constants(Vec<String>),
+}
+
+/// An argument of a function, merged across its signatures
+#[derive(Debug, PartialEq)]
+struct Arg {
+    arg_type: ArgType,
+    required: bool,
+}
+
+/// Builds a selene standard library definition, which declares the functions and properties of
+/// each class as globals
+pub fn std_file(classes: &[OutputClass], name: &str) -> String {
+    let mut lines = vec![
+        "---".to_owned(),
+        format!("name: {}", quote(name)),
+        "globals:".to_owned(),
+    ];
+
+    let mut globals = 0;
+
+    for class in classes.iter().filter(|class| !class.class.ignore) {
+        let class_name = &class.class.name;
+
+        let properties = class
+            .properties
+            .iter()
+            .filter(|property| !property.ignore && property.inherited_from.is_none());
+
+        for property in properties {
+            lines.push(format!(
+                "  {}:",
+                quote(&format!("{}.{}", class_name, property.name))
+            ));
+            lines.push(match property.readonly {
+                true => "    property: read-only".to_owned(),
+                false => "    property: full-write".to_owned(),
+            });
+            lines.extend(deprecated(property.deprecated.as_ref()));
+
+            globals += 1;
+        }
+
+        // Callbacks are implemented by users, not called, so they aren't globals
+        let functions = class.functions.iter().filter(|function| {
+            !function.ignore && !function.callback && function.inherited_from.is_none()
+        });
+
+        for function in functions {
+            lines.push(format!(
+                "  {}:",
+                quote(&format!("{}.{}", class_name, function.name))
+            ));
+            lines.extend(function_definition(function));
+
+            globals += 1;
+        }
+    }
+
+    if globals == 0 {
+        lines.pop();
+        lines.push("globals: {}".to_owned());
+    }
+
+    lines.push(String::new());
+
+    lines.join("\n")
+}
+
+fn function_definition(function: &FunctionDocEntry) -> Vec<String> {
+    let args = match function.signatures.is_empty() {
+        true => function.params.iter().map(arg).collect(),
+        false => merge_signatures(&function.signatures),
+    };
+
+    let mut lines = Vec::new();
+
+    if args.is_empty() {
+        lines.push("    args: []".to_owned());
+    } else {
+        lines.push("    args:".to_owned());
+
+        for arg in args {
+            let arg_type = match arg.arg_type {
+                ArgType::Builtin(name) => format!("type: {}", quote(name)),
+                ArgType::Display(name) => format!("type:\n          display: {}", quote(&name)),
+                ArgType::Constants(values) => format!(
+                    "type: [{}]",
+                    values
+                        .iter()
+                        .map(|value| quote(value))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+
+            match arg.required {
+                true => lines.push(format!("      - {}", arg_type)),
+                false => {
+                    lines.push("      - required: false".to_owned());
+                    lines.push(format!("        {}", arg_type));
+                }
+            }
+        }
+    }
+
+    if function.function_type == FunctionType::Method {
+        lines.push("    method: true".to_owned());
+    }
+
+    lines.extend(deprecated(function.deprecated.as_ref()));
+
+    lines
+}
+
+/// selene only knows one signature per function, so an overloaded function's args accept what
+/// any of its signatures do. An arg is only required if every signature requires it.
+fn merge_signatures(signatures: &[FunctionSignature]) -> Vec<Arg> {
+    let count = signatures
+        .iter()
+        .map(|signature| signature.params.len())
+        .max()
+        .unwrap_or(0);
+
+    (0..count)
+        .map(|index| {
+            let args: Vec<Option<Arg>> = signatures
+                .iter()
+                .map(|signature| signature.params.get(index).map(arg))
+                .collect();
+
+            let required = args
+                .iter()
+                .all(|arg| arg.as_ref().is_some_and(|arg| arg.required));
+            let mut types = args.into_iter().flatten().map(|arg| arg.arg_type);
+            let first = types.next().unwrap();
+
+            let arg_type = match types.all(|arg_type| arg_type == first) {
+                true => first,
+                false => ArgType::Builtin("any"),
+            };
+
+            Arg { arg_type, required }
+        })
+        .collect()
+}
+
+fn arg(param: &FunctionParam) -> Arg {
+    if param.variadic {
+        return Arg {
+            arg_type: ArgType::Builtin("..."),
+            required: true,
+        };
+    }
+
+    let arg_type = match &param.type_ast {
+        Some(type_ast) => arg_type(type_ast),
+        None => ArgType::Builtin("any"),
+    };
+
+    Arg {
+        arg_type,
+        required: !param.optional,
+    }
+}
+
+fn arg_type(type_ast: &TypeAst) -> ArgType {
+    match type_ast {
+        TypeAst::Reference { name } => match name.as_str() {
+            "number" | "integer" => ArgType::Builtin("number"),
+            "string" => ArgType::Builtin("string"),
+            "boolean" => ArgType::Builtin("bool"),
+            "nil" => ArgType::Builtin("nil"),
+            "table" => ArgType::Builtin("table"),
+            "function" => ArgType::Builtin("function"),
+            "any" | "unknown" => ArgType::Builtin("any"),
+            name => ArgType::Display(name.to_owned()),
+        },
+        TypeAst::Generic { name, .. } => ArgType::Display(name.clone()),
+        TypeAst::Literal { value } => match string_literal(value) {
+            Some(value) => ArgType::Constants(vec![value]),
+            None => ArgType::Builtin("bool"),
+        },
+        TypeAst::Optional { inner } => arg_type(inner),
+        TypeAst::Union { types } => union_type(types),
+        TypeAst::Function { .. } => ArgType::Builtin("function"),
+        TypeAst::Table { .. } | TypeAst::Array { .. } => ArgType::Builtin("table"),
+        TypeAst::Intersection { .. }
+        | TypeAst::Tuple { .. }
+        | TypeAst::Typeof { .. }
+        | TypeAst::Variadic { .. }
+        | TypeAst::GenericPack { .. } => ArgType::Builtin("any"),
+    }
+}
+
+/// Unions of string literals become constants. Other unions can't be checked, unless all but one
+/// of their types are `nil`, which only makes an arg optional.
+fn union_type(types: &[TypeAst]) -> ArgType {
+    let types: Vec<&TypeAst> = types
+        .iter()
+        .filter(|type_ast| !matches!(type_ast, TypeAst::Reference { name } if name == "nil"))
+        .collect();
+
+    let constants: Option<Vec<String>> = types
+        .iter()
+        .map(|type_ast| match type_ast {
+            TypeAst::Literal { value } => string_literal(value),
+            _ => None,
+        })
+        .collect();
+
+    match (types.as_slice(), constants) {
+        ([type_ast], _) => arg_type(type_ast),
+        (_, Some(constants)) if !constants.is_empty() => ArgType::Constants(constants),
+        _ => ArgType::Builtin("any"),
+    }
+}
+
+fn string_literal(value: &str) -> Option<String> {
+    let quote = value
+        .chars()
+        .next()
+        .filter(|char| matches!(char, '"' | '\''))?;
+
+    value
+        .strip_prefix(quote)
+        .and_then(|value| value.strip_suffix(quote))
+        .map(str::to_owned)
+}
+
+fn deprecated(deprecated: Option<&DeprecatedTag>) -> Vec<String> {
+    let Some(deprecated) = deprecated else {
+        return Vec::new();
+    };
+
+    let message = match (deprecated.desc, deprecated.version) {
+        (Some(desc), _) => desc.as_str().to_owned(),
+        (None, Some(version)) => format!("Deprecated since {}", version.as_str()),
+        (None, None) => "Deprecated".to_owned(),
+    };
+
+    vec![
+        "    deprecated:".to_owned(),
+        format!("      message: {}", quote(&message)),
+        "      replace: []".to_owned(),
+    ]
+}
+
+/// JSON strings are also YAML strings, and quoting every value means that names like `...` or
+/// `true` are never read as anything but strings
+fn quote(value: &str) -> String {
+    serde_json::to_string(value).unwrap()
+}
@@ -1,5 +1,6 @@
 use crate::{
-    diagnostic::Diagnostics, doc_comment::DocComment, doc_entry::DocEntry, error::Error, tags::Tag,
+    compat::Compat, diagnostic::Diagnostics, dialect::Dialect, doc_comment::DocComment,
+    doc_entry::DocEntry, error::Error, tags::Tag,
 };
 use full_moon::{
     self,
@@ -9,21 +10,108 @@ use full_moon::{
     visitors::Visitor,
 };
 
+mod exports;
+
+pub use exports::ModuleExports;
+
 #[derive(Debug)]
 pub struct SourceFile {
     doc_comments: Vec<DocComment>,
     file_id: usize,
+    exports: ModuleExports,
+}
+
+/// Gets the name a doc comment declares with a kind tag like `@class`. Unless LDoc comments are
+/// read, only block comments can declare a `@module`, since `---@module` lines are dropped for
+/// Roblox LSP.
+fn declared_name(doc_comment: &DocComment, tag: &str) -> Option<String> {
+    doc_comment
+        .comment
+        .lines()
+        .find_map(|line| {
+            line.trim_start_matches('-')
+                .trim()
+                .strip_prefix(tag)?
+                .strip_prefix(char::is_whitespace)
+        })
+        .map(|name| {
+            // EmmyLua classes can be declared like `---@class (exact) Name : Parent`
+            let name = match name.trim().strip_prefix('(') {
+                Some(rest) => rest.split_once(')').map_or(rest, |(_, name)| name),
+                None => name,
+            };
+
+            name.split(':').next().unwrap_or(name).trim().to_owned()
+        })
+}
+
+/// Whether a statement declares a local variable, like `local Fabric = {}`
+fn declares_local(stmt: Option<&Stmt>, local: &str) -> bool {
+    match stmt {
+        Some(Stmt::LocalAssignment(assignment)) => assignment
+            .names()
+            .iter()
+            .any(|name| name.token().to_string() == local),
+        _ => false,
+    }
+}
+
+/// Finds what the file returns: either a module, or the class documented on the table it returns
+fn returned_owner(doc_comments: &[DocComment], exports: &ModuleExports) -> Option<String> {
+    if let Some(module) = doc_comments
+        .iter()
+        .find_map(|doc_comment| declared_name(doc_comment, "@module"))
+    {
+        return Some(module);
+    }
+
+    let classes: Vec<_> = doc_comments
+        .iter()
+        .filter_map(|doc_comment| {
+            // LDoc declares classes with `@classmod`
+            let name = declared_name(doc_comment, "@class")
+                .or_else(|| declared_name(doc_comment, "@classmod"))?;
+
+            Some((doc_comment, name))
+        })
+        .collect();
+
+    let table = match exports.table.as_deref() {
+        Some(table) => table,
+        // A returned table constructor can only belong to a class if there's no other choice
+        None if classes.len() == 1 => return classes.into_iter().next().map(|(_, name)| name),
+        None => return None,
+    };
+
+    if let Some((_, name)) = classes.iter().find(|(_, name)| name == table) {
+        return Some(name.clone());
+    }
+
+    // Every doc comment before a statement is attached to it, so the nearest one is used
+    classes
+        .into_iter()
+        .rev()
+        .find(|(doc_comment, _)| declares_local(doc_comment.stmt.as_ref(), table))
+        .map(|(_, name)| name)
 }
 
 impl<'a> SourceFile {
-    pub fn from_str(source: &'a str, file_id: usize, relative_path: String) -> Result<Self, Error> {
-        let ast = full_moon::parse(source).map_err(|e| {
-            Error::FullMoonError(
-                e.iter()
-                    .map(|e| (relative_path.clone(), e.to_owned()))
-                    .collect::<Vec<(String, full_moon::Error)>>(),
-            )
-        })?;
+    pub fn from_str(
+        source: &'a str,
+        file_id: usize,
+        relative_path: String,
+        dialect: Dialect,
+        compat: &[Compat],
+    ) -> Result<Self, Error> {
+        let ast = full_moon::parse_fallible(source, dialect.lua_version())
+            .into_result()
+            .map_err(|e| {
+                Error::FullMoonError(
+                    e.iter()
+                        .map(|e| (relative_path.clone(), e.to_owned()))
+                        .collect::<Vec<(String, full_moon::Error)>>(),
+                )
+            })?;
 
         struct Collector<'b> {
             buffer: Vec<(Token, Option<Stmt>)>,
@@ -31,16 +119,19 @@ impl<'a> SourceFile {
             file_id: usize,
             relative_path: &'b str,
             doc_comments: Vec<DocComment>,
+            /// Whether `--` lines continue a doc comment started with `---`, like in LDoc
+            ldoc: bool,
         }
 
         impl<'b> Collector<'b> {
-            fn new(file_id: usize, relative_path: &'b str) -> Self {
+            fn new(file_id: usize, relative_path: &'b str, ldoc: bool) -> Self {
                 Self {
                     buffer: Vec::new(),
                     file_id,
                     last_line: 0,
                     relative_path,
                     doc_comments: Vec::new(),
+                    ldoc,
                 }
             }
 
@@ -73,15 +164,18 @@ impl<'a> SourceFile {
                                     comment.find(|char: char| !char.is_whitespace())
                                 {
                                     // Compatibility: Drop lines like `---@module <path>` used
-                                    // for Roblox LSP comments (#39)
+                                    // for Roblox LSP comments (#39). LDoc declares modules
+                                    // this way, so they're kept when reading LDoc comments.
                                     let tag_body = &comment[first_non_whitespace..];
 
-                                    if tag_body.starts_with("@module") {
+                                    if tag_body.starts_with("@module") && !self.ldoc {
                                         return;
                                     }
                                 }
                             }
 
+                            self.buffer.push((token, stmt));
+                        } else if self.ldoc && !self.buffer.is_empty() {
                             self.buffer.push((token, stmt));
                         } else if let Some(doc_comment) = self.flush() {
                             self.doc_comments.push(doc_comment);
@@ -171,28 +265,33 @@ impl<'a> SourceFile {
             }
         }
 
-        let mut collector = Collector::new(file_id, &relative_path);
+        let mut collector = Collector::new(file_id, &relative_path, compat.contains(&Compat::LDoc));
 
         collector.visit_ast(&ast);
 
         let doc_comments = collector.finish();
 
+        let mut exports = ModuleExports::from_ast(&ast);
+        exports.owner = returned_owner(&doc_comments, &exports);
+
         Ok(Self {
             doc_comments,
             file_id,
+            exports,
         })
     }
 
-    pub fn parse(&'a self) -> Result<(Vec<DocEntry>, Vec<Tag>), Error> {
+    pub fn parse(&'a self, compat: &[Compat]) -> Result<(Vec<DocEntry<'a>>, Vec<Tag<'a>>), Error> {
         let (doc_entries, errors): (Vec<_>, Vec<_>) = self
             .doc_comments
             .iter()
-            .map(DocEntry::parse)
+            .map(|doc_comment| DocEntry::parse(doc_comment, &self.exports, compat))
             .partition(Result::is_ok);
 
         let (doc_entries, tags): (Vec<_>, Vec<_>) =
             doc_entries.into_iter().map(Result::unwrap).unzip();
 
+        let doc_entries: Vec<DocEntry> = doc_entries.into_iter().flatten().collect();
         let tags: Vec<Tag> = tags.into_iter().flatten().collect();
 
         let errors: Diagnostics = errors
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use full_moon::ast::{self, Ast, Expression, LastStmt, Stmt, Var};
+
+/// What a file exports through the value it returns, used to infer `@within` for doc comments
+/// that don't have one
+#[derive(Debug, Default)]
+pub struct ModuleExports {
+    /// The documented class or module that the file returns
+    pub owner: Option<String>,
+    /// The local name of the table the file returns, like `Util` in `return Util`
+    pub table: Option<String>,
+    /// Local functions exported through the returned table, keyed by their local name, along
+    /// with the name they are exported as
+    pub locals: HashMap<String, String>,
+}
+
+/// Gets the name of a plain variable, like `foo` but not `foo.bar`
+fn variable_name(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Var(Var::Name(name)) => Some(name.token().to_string()),
+        _ => None,
+    }
+}
+
+/// Splits an assignment target like `Table.key` into its table and key
+fn table_key(var: &Var) -> Option<(String, String)> {
+    let Var::Expression(var_expression) = var else {
+        return None;
+    };
+
+    let ast::Prefix::Name(table) = var_expression.prefix() else {
+        return None;
+    };
+
+    let mut suffixes = var_expression.suffixes();
+
+    match (suffixes.next(), suffixes.next()) {
+        (Some(ast::Suffix::Index(ast::Index::Dot { name, .. })), None) => {
+            Some((table.token().to_string(), name.token().to_string()))
+        }
+        _ => None,
+    }
+}
+
+impl ModuleExports {
+    /// Finds what the file returns. `return Table` exports the locals assigned into `Table`, and
+    /// `return { key = value }` exports the locals listed in it.
+    pub fn from_ast(ast: &Ast) -> Self {
+        let mut exports = Self::default();
+        let block = ast.nodes();
+
+        let returned = match block.last_stmt() {
+            Some(LastStmt::Return(ret)) if ret.returns().len() == 1 => {
+                ret.returns().iter().next().unwrap()
+            }
+            _ => return exports,
+        };
+
+        match returned {
+            Expression::TableConstructor(table) => {
+                for field in table.fields() {
+                    if let ast::Field::NameKey { key, value, .. } = field {
+                        if let Some(local) = variable_name(value) {
+                            exports.locals.insert(local, key.token().to_string());
+                        }
+                    }
+                }
+            }
+            expression => {
+                let Some(table_name) = variable_name(expression) else {
+                    return exports;
+                };
+
+                for stmt in block.stmts() {
+                    let Stmt::Assignment(assignment) = stmt else {
+                        continue;
+                    };
+
+                    let targets = assignment.variables().iter().map(table_key);
+                    let values = assignment.expressions().iter().map(variable_name);
+
+                    for (target, value) in targets.zip(values) {
+                        if let (Some((table, key)), Some(local)) = (target, value) {
+                            if table == table_name {
+                                exports.locals.insert(local, key);
+                            }
+                        }
+                    }
+                }
+
+                exports.table = Some(table_name);
+            }
+        }
+
+        exports
+    }
+
+    /// Finds the class and name a local function is exported as
+    pub fn exported_local(&self, local: &str) -> Option<(String, String)> {
+        let owner = self.owner.as_ref()?;
+
+        self.locals
+            .get(local)
+            .map(|name| (owner.clone(), name.clone()))
+    }
+
+    /// Replaces the returned table's local name at the start of a path like `Util.strings` with
+    /// the name of the class or module it documents
+    pub fn qualify(&self, path: &str) -> Option<String> {
+        let owner = self.owner.as_ref()?;
+        let rest = path.strip_prefix(self.table.as_ref()?.as_str())?;
+
+        if rest.is_empty() || rest.starts_with('.') {
+            Some(format!("{}{}", owner, rest))
+        } else {
+            None
+        }
+    }
+}
@@ -2,15 +2,23 @@ use crate::{diagnostic::Diagnostic, doc_entry::FunctionType, span::Span};
 use serde::Serialize;
 use std::convert::TryFrom;
 
+mod callback;
 mod class;
 mod custom;
+mod emmylua;
 mod error;
+mod event;
+mod example;
+mod extends;
 mod external;
 mod field;
 mod function;
+mod implements;
 mod index;
 mod interface;
+mod ldoc;
 mod marker;
+mod module;
 mod param;
 mod property;
 mod return_tag;
@@ -19,17 +27,26 @@ mod type_tag;
 mod validation;
 mod within;
 
+pub use callback::CallbackTag;
 pub use class::ClassTag;
 pub use custom::CustomTag;
+pub use emmylua::{is_private_field, parse_emmylua_tag};
 pub use error::ErrorTag;
+pub use event::EventTag;
+pub use example::{is_example_tag, take_example_block, ExampleTag};
+pub use extends::ExtendsTag;
 pub use external::ExternalTag;
 pub use field::FieldTag;
 pub use function::FunctionTag;
+pub use implements::ImplementsTag;
 pub use index::IndexTag;
 pub use interface::InterfaceTag;
+pub use ldoc::{is_usage_tag, parse_ldoc_tag, take_usage_block};
 pub use marker::{
-    ClientTag, IgnoreTag, PluginTag, PrivateTag, ReadOnlyTag, ServerTag, UnreleasedTag, YieldsTag,
+    ClientTag, ConstructorTag, IgnoreTag, OverloadTag, PluginTag, PrivateTag, ReadOnlyTag,
+    ServerTag, UnreleasedTag, YieldsTag,
 };
+pub use module::ModuleTag;
 pub use param::ParamTag;
 pub use property::PropertyTag;
 pub use return_tag::ReturnTag;
@@ -91,6 +108,7 @@ define_tags! {
     Ignore(IgnoreTag),
     Yields(YieldsTag),
     ReadOnly(ReadOnlyTag),
+    Overload(OverloadTag),
     Return(ReturnTag),
     Deprecated(DeprecatedTag),
     Since(SinceTag),
@@ -98,12 +116,28 @@ define_tags! {
     Error(ErrorTag),
     Index(IndexTag),
     External(ExternalTag),
+    Example(ExampleTag),
+    Extends(ExtendsTag),
+    Implements(ImplementsTag),
+    Module(ModuleTag),
+    Event(EventTag),
+    Callback(CallbackTag),
+    Constructor(ConstructorTag),
 
     // Unimplemented:
     // Link,
     // Enum,
 }
 
+/// The type of a param, return, field or property tag in Luau syntax. Formats that write types
+/// differently, like EmmyLua's `string[]`, fill in `translated_type`, while `lua_type` keeps the
+/// type as it was written, for diagnostics.
+pub fn luau_type(lua_type: Span, translated_type: &Option<String>) -> String {
+    translated_type
+        .clone()
+        .unwrap_or_else(|| lua_type.to_string())
+}
+
 impl<'a> TryFrom<Span<'a>> for Tag<'a> {
     type Error = Diagnostic;
 
@@ -135,19 +169,26 @@ impl<'a> TryFrom<Span<'a>> for Tag<'a> {
             "@yields" => YieldsTag::parse().map(Tag::Yields),
             "@readonly" => ReadOnlyTag::parse().map(Tag::ReadOnly),
             "@unreleased" => UnreleasedTag::parse().map(Tag::Unreleased),
+            "@overload" => OverloadTag::parse().map(Tag::Overload),
+            "@constructor" => ConstructorTag::parse().map(Tag::Constructor),
 
             "@param" => ParamTag::parse(tag_text()?).map(Tag::Param),
             "@return" => ReturnTag::parse(tag_text()?).map(Tag::Return),
             "@within" => WithinTag::parse(tag_text()?).map(Tag::Within),
             "@__index" => IndexTag::parse(tag_text()?).map(Tag::Index),
+            "@extends" => ExtendsTag::parse(tag_text()?).map(Tag::Extends),
+            "@implements" => ImplementsTag::parse(tag_text()?).map(Tag::Implements),
             "@type" => TypeTag::parse(tag_text()?).map(Tag::Type),
             "@interface" => InterfaceTag::parse(tag_text()?).map(Tag::Interface),
             "@field" => FieldTag::parse(tag_text()?).map(Tag::Field),
             "@prop" => PropertyTag::parse(tag_text()?).map(Tag::Property),
+            "@event" | "@signal" => EventTag::parse(tag_text()?).map(Tag::Event),
             "@class" => ClassTag::parse(tag_text()?).map(Tag::Class),
+            "@module" => ModuleTag::parse(tag_text()?).map(Tag::Module),
             "@external" => ExternalTag::parse(tag_text()?).map(Tag::External),
             "@function" => FunctionTag::parse(tag_text()?, FunctionType::Static).map(Tag::Function),
             "@method" => FunctionTag::parse(tag_text()?, FunctionType::Method).map(Tag::Function),
+            "@callback" => CallbackTag::parse(tag_text()?).map(Tag::Callback),
             "@deprecated" => DeprecatedTag::parse(tag_text()?).map(Tag::Deprecated),
             "@since" => SinceTag::parse(tag_text()?).map(Tag::Since),
             "@tag" => CustomTag::parse(tag_text()?).map(Tag::Custom),
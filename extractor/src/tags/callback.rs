@@ -0,0 +1,20 @@
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+/// Declares a callback signature that users implement, rather than a function they call
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct CallbackTag<'a> {
+    pub name: Span<'a>,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+impl<'a> CallbackTag<'a> {
+    pub fn parse(text: Span<'a>) -> Result<Self, Diagnostic> {
+        Ok(Self {
+            name: text,
+            source: text,
+        })
+    }
+}
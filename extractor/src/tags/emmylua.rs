@@ -0,0 +1,632 @@
+use std::convert::TryFrom;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+use super::{
+    ClassTag, DeprecatedTag, ExtendsTag, FieldTag, ParamTag, PrivateTag, ReturnTag, Tag, YieldsTag,
+};
+
+/// Annotations that only matter to the language server, so they are left out of the docs
+const IGNORED_ANNOTATIONS: &[&str] = &[
+    "@alias",
+    "@as",
+    "@cast",
+    "@diagnostic",
+    "@enum",
+    "@generic",
+    "@meta",
+    "@nodiscard",
+    "@operator",
+    "@overload",
+    "@see",
+    "@source",
+    "@type",
+    "@vararg",
+    "@version",
+];
+
+/// Splits the type off the start of an annotation. Types end at the first space that isn't
+/// inside brackets or a string, or next to a `|`, `,` or `:` that continues the type.
+pub(super) fn split_type(span: Span) -> (Span, Span) {
+    let text = span.as_str();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut end = text.len();
+
+    for (index, char) in text.char_indices() {
+        match (quote, char) {
+            (Some(open), _) if char == open => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'' | '`') => quote = Some(char),
+            (None, '(' | '{' | '[' | '<') => depth += 1,
+            (None, ')' | '}' | ']' | '>') => depth -= 1,
+            (None, char) if char.is_whitespace() && depth <= 0 => {
+                let before = text[..index].trim_end();
+                let after = text[index..].trim_start();
+
+                if !before.ends_with(['|', ',', ':']) && !after.starts_with(['|', ':']) {
+                    end = index;
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let lua_type = span.slice(0, end).trim();
+    let rest = span.slice(end, text.len() - end).trim();
+
+    (lua_type, rest)
+}
+
+/// Translates a lua-language-server type into Luau, like `string[]` into `{string}`,
+/// `fun(a: A): R` into `(a: A) -> R` and `table<K, V>` into `{[K]: V}`. Types that don't use any
+/// of those, or that can't be read, are left as they are.
+pub(super) fn translate_type(lua_type: &str) -> Option<String> {
+    if !["[]", "fun(", "table<"]
+        .iter()
+        .any(|syntax| lua_type.contains(syntax))
+    {
+        return None;
+    }
+
+    let mut translator = TypeTranslator {
+        text: lua_type,
+        position: 0,
+    };
+
+    let (luau_type, _) = translator.union()?;
+    translator.skip_whitespace();
+
+    (translator.position == lua_type.len()).then_some(luau_type)
+}
+
+/// Reads a lua-language-server type, writing it back out as Luau. Each type is returned along
+/// with whether it's a function type, which needs parentheses within unions and optionals.
+struct TypeTranslator<'s> {
+    text: &'s str,
+    position: usize,
+}
+
+impl<'s> TypeTranslator<'s> {
+    fn rest(&self) -> &'s str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.position = self.text.len() - self.rest().trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        match self.rest().starts_with(token) {
+            true => {
+                self.position += token.len();
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Option<()> {
+        self.eat(token).then_some(())
+    }
+
+    fn name(&mut self) -> Option<String> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let len = rest
+            .find(|char: char| !(char.is_alphanumeric() || char == '_' || char == '.'))
+            .unwrap_or(rest.len());
+
+        if len == 0 || rest.starts_with(|char: char| char.is_ascii_digit()) {
+            return None;
+        }
+
+        self.position += len;
+        Some(rest[..len].to_owned())
+    }
+
+    /// Whether the next thing is a name followed by `:`, like the next param of a function type
+    fn at_named_item(&mut self) -> bool {
+        let start = self.position;
+        let named = self.name().is_some() && (self.eat(":") || (self.eat("?") && self.eat(":")));
+        self.position = start;
+
+        named
+    }
+
+    fn union(&mut self) -> Option<(String, bool)> {
+        let mut types = vec![self.postfix()?];
+
+        while self.eat("|") {
+            types.push(self.postfix()?);
+        }
+
+        if types.len() == 1 {
+            return types.pop();
+        }
+
+        let types: Vec<String> = types
+            .into_iter()
+            .map(|(luau_type, function)| match function {
+                true => format!("({})", luau_type),
+                false => luau_type,
+            })
+            .collect();
+
+        Some((types.join(" | "), false))
+    }
+
+    fn postfix(&mut self) -> Option<(String, bool)> {
+        let (mut luau_type, mut function) = self.primary()?;
+
+        loop {
+            if self.rest().starts_with("[]") {
+                self.position += 2;
+                luau_type = format!("{{{}}}", luau_type);
+            } else if self.rest().starts_with('?') {
+                self.position += 1;
+                luau_type = match function {
+                    true => format!("({})?", luau_type),
+                    false => format!("{}?", luau_type),
+                };
+            } else {
+                return Some((luau_type, function));
+            }
+
+            function = false;
+        }
+    }
+
+    fn primary(&mut self) -> Option<(String, bool)> {
+        self.skip_whitespace();
+
+        if self.rest().starts_with("fun(") {
+            self.position += "fun".len();
+            return self.function().map(|luau_type| (luau_type, true));
+        }
+
+        if self.eat("table<") {
+            let (key, _) = self.union()?;
+            self.expect(",")?;
+            let (value, _) = self.union()?;
+            self.expect(">")?;
+
+            return Some((format!("{{[{}]: {}}}", key, value), false));
+        }
+
+        if self.eat("(") {
+            let (inner, _) = self.union()?;
+            self.expect(")")?;
+
+            return Some((format!("({})", inner), false));
+        }
+
+        if self.eat("{") {
+            return self.table().map(|luau_type| (luau_type, false));
+        }
+
+        if let Some(quote) = self
+            .rest()
+            .chars()
+            .next()
+            .filter(|char| matches!(char, '"' | '\''))
+        {
+            let len = self.rest()[1..].find(quote)? + 2;
+            let literal = self.rest()[..len].to_owned();
+            self.position += len;
+
+            return Some((literal, false));
+        }
+
+        if self.eat("...") {
+            return Some(("...any".to_owned(), false));
+        }
+
+        let name = self.name()?;
+
+        if !self.eat("<") {
+            return Some((name, false));
+        }
+
+        let mut generics = vec![self.union()?.0];
+
+        while self.eat(",") {
+            generics.push(self.union()?.0);
+        }
+
+        self.expect(">")?;
+
+        Some((format!("{}<{}>", name, generics.join(", ")), false))
+    }
+
+    /// `fun(a: A, b?: B, ...: C): R, S`, after the `fun`
+    fn function(&mut self) -> Option<String> {
+        self.expect("(")?;
+
+        let mut params = Vec::new();
+
+        while !self.eat(")") {
+            if !params.is_empty() {
+                self.expect(",")?;
+            }
+
+            let name = match self.eat("...") {
+                true => "...".to_owned(),
+                false => self.name()?,
+            };
+            let optional = self.eat("?");
+
+            let lua_type = match self.eat(":") {
+                true => self.union()?,
+                false => ("any".to_owned(), false),
+            };
+
+            params.push(match (name.as_str(), lua_type) {
+                ("...", (lua_type, _)) => format!("...{}", lua_type),
+                (_, (lua_type, true)) if optional => format!("{}: ({})?", name, lua_type),
+                (_, (lua_type, _)) if optional => format!("{}: {}?", name, lua_type),
+                (_, (lua_type, _)) => format!("{}: {}", name, lua_type),
+            });
+        }
+
+        let mut returns = Vec::new();
+
+        if self.eat(":") {
+            returns.push(self.union()?.0);
+
+            loop {
+                let start = self.position;
+
+                // A comma followed by a name and `:` is the next param of an enclosing function
+                if !self.eat(",") || self.at_named_item() {
+                    self.position = start;
+                    break;
+                }
+
+                returns.push(self.union()?.0);
+            }
+        }
+
+        let returns = match returns.as_slice() {
+            [luau_type] if !luau_type.contains(" | ") => luau_type.clone(),
+            returns => format!("({})", returns.join(", ")),
+        };
+
+        Some(format!("({}) -> {}", params.join(", "), returns))
+    }
+
+    /// `{ name: T, [K]: V }`, after the `{`
+    fn table(&mut self) -> Option<String> {
+        let mut fields = Vec::new();
+
+        while !self.eat("}") {
+            if !fields.is_empty() && !self.eat(",") {
+                self.expect(";")?;
+            }
+
+            if self.eat("}") {
+                break;
+            }
+
+            if self.eat("[") {
+                let (key, _) = self.union()?;
+                self.expect("]")?;
+                self.expect(":")?;
+                let (value, _) = self.union()?;
+
+                fields.push(format!("[{}]: {}", key, value));
+            } else if self.at_named_item() {
+                let name = self.name()?;
+                self.expect(":")?;
+                let (value, _) = self.union()?;
+
+                fields.push(format!("{}: {}", name, value));
+            } else {
+                fields.push(self.union()?.0);
+            }
+        }
+
+        match fields.is_empty() {
+            true => Some("{}".to_owned()),
+            false => Some(format!("{{ {} }}", fields.join(", "))),
+        }
+    }
+}
+
+/// Removes the `#` or `--` that can come before a description
+pub(super) fn description(span: Span) -> Span {
+    span.strip_prefix("#")
+        .or_else(|| span.strip_prefix("--"))
+        .map(Span::trim)
+        .unwrap_or(span)
+}
+
+fn param<'a>(text: Span<'a>, rest: Span<'a>) -> Result<Tag<'a>, Diagnostic> {
+    let mut pieces = rest.splitn(2, " ");
+    let name = pieces.next().unwrap().trim();
+    let (lua_type, desc) = match pieces.next() {
+        Some(rest) => split_type(rest.trim()),
+        None => return Err(text.diagnostic("Param type is required")),
+    };
+
+    if name.is_empty() || lua_type.is_empty() {
+        return Err(text.diagnostic("Param type is required"));
+    }
+
+    Ok(Tag::Param(ParamTag {
+        name,
+        desc: description(desc),
+        lua_type,
+        translated_type: translate_type(lua_type.as_str()),
+        default: None,
+        optional: false,
+        source: text,
+    }))
+}
+
+fn is_name(text: &str) -> bool {
+    text.starts_with(|char: char| char.is_alphabetic() || char == '_')
+        && text
+            .chars()
+            .all(|char| char.is_alphanumeric() || char == '_')
+}
+
+fn return_tag<'a>(text: Span<'a>, rest: Span<'a>) -> Result<Tag<'a>, Diagnostic> {
+    let (lua_type, desc) = split_type(rest);
+
+    if lua_type.is_empty() {
+        return Err(text.diagnostic("Return type is required"));
+    }
+
+    // `---@return number count # The count` names the return value before the description, and
+    // `---@return number count` names it without one
+    let desc = match desc.as_str().find('#') {
+        Some(index) => desc.slice(index + 1, desc.len() - index - 1).trim(),
+        None if is_name(desc.as_str()) => Span::empty(text.file_id),
+        None => description(desc),
+    };
+
+    Ok(Tag::Return(ReturnTag {
+        desc,
+        lua_type,
+        translated_type: translate_type(lua_type.as_str()),
+        source: text,
+    }))
+}
+
+/// `---@class (exact) Name : Parent` becomes a class tag, along with an extends tag if it has a
+/// parent
+fn class<'a>(text: Span<'a>, rest: Span<'a>) -> Vec<Result<Tag<'a>, Diagnostic>> {
+    let rest = match rest.as_str().find(')') {
+        Some(index) if rest.starts_with('(') => rest.slice(index + 1, rest.len() - index - 1),
+        _ => rest,
+    };
+
+    let mut pieces = rest.splitn(2, ":");
+    let name = pieces.next().unwrap().trim();
+
+    if name.is_empty() {
+        return vec![Err(text.diagnostic("Class name is required"))];
+    }
+
+    let mut tags = vec![Ok(Tag::Class(ClassTag { name, source: text }))];
+
+    if let Some(parents) = pieces.next().map(Span::trim) {
+        if parents.contains(',') {
+            tags.push(Err(
+                parents.diagnostic("A class can only extend one parent class")
+            ));
+        } else {
+            tags.push(ExtendsTag::parse(parents).map(Tag::Extends));
+        }
+    }
+
+    tags
+}
+
+/// `---@field [scope] name type [desc]`. Index signatures like `---@field [string] number` don't
+/// document a member, so they are left out.
+fn field<'a>(text: Span<'a>, rest: Span<'a>) -> Option<Result<Tag<'a>, Diagnostic>> {
+    let rest = match rest.splitn(2, " ").next() {
+        Some(scope) if is_scope(scope.as_str()) => {
+            rest.slice(scope.len(), rest.len() - scope.len())
+        }
+        _ => rest,
+    }
+    .trim();
+
+    if rest.starts_with('[') {
+        return None;
+    }
+
+    let mut pieces = rest.splitn(2, " ");
+    let name = pieces.next().unwrap().trim();
+    let (lua_type, desc) = match pieces.next() {
+        Some(rest) => split_type(rest.trim()),
+        None => return Some(Err(text.diagnostic("Field type is required"))),
+    };
+
+    // Properties can't be marked optional, so `name?` is documented as `name`
+    let name = match name.ends_with('?') {
+        true => name.slice(0, name.len() - 1),
+        false => name,
+    };
+
+    Some(Ok(Tag::Field(FieldTag {
+        name,
+        desc: description(desc),
+        lua_type,
+        translated_type: translate_type(lua_type.as_str()),
+        source: text,
+    })))
+}
+
+fn is_scope(word: &str) -> bool {
+    matches!(word, "public" | "private" | "protected" | "package")
+}
+
+/// Whether a field translated from `---@field` was declared as not public
+pub fn is_private_field(field: &FieldTag) -> bool {
+    field
+        .source
+        .as_str()
+        .split_whitespace()
+        .nth(1)
+        .is_some_and(|scope| is_scope(scope) && scope != "public")
+}
+
+/// Translates an EmmyLua or lua-language-server annotation, with its `---` already removed, into
+/// Moonwave tags. Annotations that Moonwave has no use for produce no tags, and anything that
+/// isn't an annotation is parsed as a Moonwave tag.
+pub fn parse_emmylua_tag(text: Span<'_>) -> Vec<Result<Tag<'_>, Diagnostic>> {
+    let mut pieces = text.splitn(2, " ");
+    let tag_name = pieces.next().unwrap().trim();
+    let rest = pieces
+        .next()
+        .map(Span::trim)
+        .unwrap_or_else(|| Span::empty(text.file_id));
+
+    match tag_name.as_str() {
+        "@param" => vec![param(text, rest)],
+        "@return" => vec![return_tag(text, rest)],
+        "@class" => class(text, rest),
+        "@field" => field(text, rest).into_iter().collect(),
+        "@async" => vec![Ok(Tag::Yields(YieldsTag { source: text }))],
+        "@private" | "@protected" | "@package" => {
+            vec![Ok(Tag::Private(PrivateTag { source: text }))]
+        }
+        "@deprecated" => vec![Ok(Tag::Deprecated(DeprecatedTag {
+            version: None,
+            desc: (!rest.is_empty()).then_some(rest),
+            source: text,
+        }))],
+        name if IGNORED_ANNOTATIONS.contains(&name) => Vec::new(),
+        _ => vec![Tag::try_from(text)],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    fn parse(text: &str) -> Vec<Tag<'_>> {
+        parse_emmylua_tag(Span::dummy(text))
+            .into_iter()
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn param() {
+        assert_yaml_snapshot!(parse("@param name string The name to use"), @r###"
+        ---
+        - Param:
+            name: name
+            desc: The name to use
+            lua_type: string
+        "###);
+
+        assert_yaml_snapshot!(parse("@param callback? fun(a: number, b: string): boolean # Called after"), @r###"
+        ---
+        - Param:
+            name: callback?
+            desc: Called after
+            lua_type: "fun(a: number, b: string): boolean"
+        "###);
+
+        assert_yaml_snapshot!(parse("@param value string | nil"), @r###"
+        ---
+        - Param:
+            name: value
+            desc: ""
+            lua_type: string | nil
+        "###);
+    }
+
+    #[test]
+    fn return_value() {
+        assert_yaml_snapshot!(parse("@return table<string, number> counts # How many of each"), @r###"
+        ---
+        - Return:
+            desc: How many of each
+            lua_type: "table<string, number>"
+        "###);
+    }
+
+    #[test]
+    fn return_name() {
+        assert_yaml_snapshot!(parse("@return string[] names"), @r###"
+        ---
+        - Return:
+            desc: ""
+            lua_type: "string[]"
+        "###);
+    }
+
+    #[test]
+    fn types() {
+        let translate = translate_type;
+
+        assert_eq!(translate("string[]"), Some("{string}".to_owned()));
+        assert_eq!(
+            translate("(string|number)[]"),
+            Some("{(string | number)}".to_owned())
+        );
+        assert_eq!(
+            translate("fun(a: number, b?: string): boolean"),
+            Some("(a: number, b: string?) -> boolean".to_owned())
+        );
+        assert_eq!(
+            translate("fun(...: any): string, number"),
+            Some("(...any) -> (string, number)".to_owned())
+        );
+        assert_eq!(
+            translate("fun(callback: fun(): Dog, count: integer)|nil"),
+            Some("((callback: () -> Dog, count: integer) -> ()) | nil".to_owned())
+        );
+        assert_eq!(
+            translate("table<string, Dog[]>"),
+            Some("{[string]: {Dog}}".to_owned())
+        );
+        assert_eq!(translate("string|nil"), None);
+    }
+
+    #[test]
+    fn class() {
+        assert_yaml_snapshot!(parse("@class (exact) Dog : Animal"), @r###"
+        ---
+        - Class:
+            name: Dog
+        - Extends:
+            name: Animal
+        "###);
+    }
+
+    #[test]
+    fn field() {
+        let tags = parse("@field private legs integer How many legs it has");
+
+        assert_yaml_snapshot!(tags, @r###"
+        ---
+        - Field:
+            name: legs
+            desc: How many legs it has
+            lua_type: integer
+        "###);
+
+        assert!(matches!(&tags[0], Tag::Field(field) if is_private_field(field)));
+        assert!(parse("@field [string] number").is_empty());
+    }
+
+    #[test]
+    fn ignored() {
+        assert!(parse("@meta").is_empty());
+        assert!(parse("@type number").is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+/// Declares an event with `@event` or its alias `@signal`
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct EventTag<'a> {
+    pub name: Span<'a>,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+impl<'a> EventTag<'a> {
+    pub fn parse(span: Span<'a>) -> Result<Self, Diagnostic> {
+        if span.contains(char::is_whitespace) {
+            return Err(span.diagnostic(
+                "Events are named without a type. Document the arguments they fire with using @param",
+            ));
+        }
+
+        Ok(Self {
+            name: span,
+            source: span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn snapshot() {
+        assert_yaml_snapshot!(EventTag::parse(Span::dummy("Changed")), @r###"
+        ---
+        Ok:
+          name: Changed
+        "###);
+
+        assert!(EventTag::parse(Span::dummy("Changed Signal<string>")).is_err());
+    }
+}
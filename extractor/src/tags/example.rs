@@ -0,0 +1,226 @@
+use std::iter::Peekable;
+
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct ExampleTag<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Span<'a>>,
+    pub language: String,
+    pub code: String,
+
+    /// The lines of the doc comment that `code` was joined from
+    #[serde(skip)]
+    pub code_lines: Vec<Span<'a>>,
+    /// Whether the block has `ignore` in its info string, like ```lua ignore, so its code isn't
+    /// checked
+    #[serde(skip)]
+    pub ignore: bool,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+fn fence_marker(line: &str) -> Option<&'static str> {
+    let line = line.trim_start();
+
+    if line.starts_with("```") {
+        Some("```")
+    } else if line.starts_with("~~~") {
+        Some("~~~")
+    } else {
+        None
+    }
+}
+
+fn is_blank(line: &Span) -> bool {
+    line.chars().all(char::is_whitespace)
+}
+
+fn is_indented(line: &Span) -> bool {
+    line.starts_with(char::is_whitespace) && !is_blank(line)
+}
+
+/// Whether a line of a doc comment starts an `@example` tag
+pub fn is_example_tag(line: &Span) -> bool {
+    line.as_str() == "@example" || line.starts_with("@example ")
+}
+
+/// Takes the lines of the code block that follows an `@example` tag, which is either a fenced
+/// block or a block of indented lines. Blank lines before the block are skipped.
+pub fn take_example_block<'a>(
+    lines: &mut Peekable<impl Iterator<Item = Span<'a>>>,
+) -> Vec<Span<'a>> {
+    let mut block = Vec::new();
+
+    while lines.next_if(is_blank).is_some() {}
+
+    match lines.peek().and_then(|line| fence_marker(line)) {
+        Some(marker) => {
+            block.extend(lines.next());
+
+            for line in lines.by_ref() {
+                let is_closing = fence_marker(&line) == Some(marker)
+                    && line
+                        .trim_start()
+                        .trim_start_matches(&marker[..1])
+                        .trim()
+                        .is_empty();
+
+                block.push(line);
+
+                if is_closing {
+                    break;
+                }
+            }
+        }
+        None => {
+            while let Some(line) = lines.next_if(|line| is_indented(line) || is_blank(line)) {
+                block.push(line);
+            }
+
+            while block.last().is_some_and(is_blank) {
+                block.pop();
+            }
+        }
+    }
+
+    block
+}
+
+/// Removes the indentation that every non-blank line has in common
+fn dedent(lines: Vec<Span>) -> Vec<Span> {
+    let indentation = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .into_iter()
+        .map(|line| {
+            let indentation = indentation.min(line.len());
+
+            line.slice(indentation, line.len() - indentation)
+        })
+        .collect()
+}
+
+impl<'a> ExampleTag<'a> {
+    /// Parses an `@example [title]` line along with the block taken by [take_example_block]
+    pub fn parse(source: Span<'a>, block: Vec<Span<'a>>) -> Result<Self, Diagnostic> {
+        let title = source
+            .strip_prefix("@example")
+            .map(Span::trim)
+            .filter(|title| !title.is_empty());
+
+        let (language, ignore, code_lines) = match block.first().and_then(|line| fence_marker(line))
+        {
+            Some(marker) => {
+                let mut lines = block.into_iter();
+                let opening = lines.next().unwrap();
+                let mut code_lines: Vec<_> = lines.collect();
+
+                let is_closed = !code_lines.is_empty()
+                    && fence_marker(code_lines.last().unwrap()) == Some(marker);
+
+                if !is_closed {
+                    return Err(opening.diagnostic("This code block is never closed"));
+                }
+
+                code_lines.pop();
+
+                let mut info = opening
+                    .trim_start()
+                    .trim_start_matches(&marker[..1])
+                    .split(|char: char| char.is_whitespace() || char == ',')
+                    .filter(|word| !word.is_empty());
+
+                let language = info.next().unwrap_or("lua").to_owned();
+                let ignore = info.any(|word| word == "ignore");
+
+                (language, ignore, code_lines)
+            }
+            None if block.is_empty() => {
+                return Err(source
+                    .diagnostic("An example must be followed by an indented or fenced code block"))
+            }
+            None => ("lua".to_owned(), false, dedent(block)),
+        };
+
+        Ok(Self {
+            title,
+            language,
+            code: code_lines
+                .iter()
+                .map(Span::as_str)
+                .collect::<Vec<_>>()
+                .join("\n"),
+            code_lines,
+            ignore,
+            source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    fn parse(text: &str) -> Result<ExampleTag<'_>, Diagnostic> {
+        let mut lines = Span::dummy(text).lines().peekable();
+        let source = lines.next().unwrap();
+        let block = take_example_block(&mut lines);
+
+        ExampleTag::parse(source, block)
+    }
+
+    #[test]
+    fn indented() {
+        let value =
+            parse("@example Adding numbers\n\n\tlocal x = 1\n\n\t\tprint(x + 1)\n\nNot code");
+
+        assert_yaml_snapshot!(value, @r###"
+        ---
+        Ok:
+          title: Adding numbers
+          language: lua
+          code: "local x = 1\n\n\tprint(x + 1)"
+        "###);
+    }
+
+    #[test]
+    fn fenced() {
+        let value = parse("@example\n```luau\nlocal x: number = 1\n```\nNot code");
+
+        assert_yaml_snapshot!(value, @r###"
+        ---
+        Ok:
+          language: luau
+          code: "local x: number = 1"
+        "###);
+    }
+
+    #[test]
+    fn ignored() {
+        let example = parse("@example\n```lua ignore\nlocal x =\n```").unwrap();
+
+        assert_eq!(example.language, "lua");
+        assert!(example.ignore);
+        assert!(!parse("@example\n```lua\nlocal x = 1\n```").unwrap().ignore);
+    }
+
+    #[test]
+    fn unclosed() {
+        assert!(parse("@example\n```lua\nlocal x = 1").is_err());
+    }
+
+    #[test]
+    fn no_block() {
+        assert!(parse("@example Title\nNot code").is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct ExtendsTag<'a> {
+    pub name: Span<'a>,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+impl<'a> ExtendsTag<'a> {
+    pub fn parse(span: Span<'a>) -> Result<Self, Diagnostic> {
+        if span.contains(char::is_whitespace) {
+            return Err(span.diagnostic("A class can only extend one parent class"));
+        }
+
+        Ok(Self {
+            name: span,
+            source: span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn snapshot() {
+        assert_yaml_snapshot!(ExtendsTag::parse(Span::dummy("BaseComponent")), @r###"
+        ---
+        Ok:
+          name: BaseComponent
+        "###);
+
+        assert_yaml_snapshot!(ExtendsTag::parse(Span::dummy("Button IconButton")), @r###"
+        ---
+        Err:
+          text: A class can only extend one parent class
+          start: 0
+          len: 17
+          file_id: 0
+          additional_diagnostics: []
+        "###);
+    }
+}
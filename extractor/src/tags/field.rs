@@ -8,6 +8,8 @@ pub struct FieldTag<'a> {
     pub desc: Span<'a>,
     pub lua_type: Span<'a>,
     #[serde(skip)]
+    pub translated_type: Option<String>,
+    #[serde(skip)]
     pub source: Span<'a>,
 }
 
@@ -36,6 +38,7 @@ impl<'a> FieldTag<'a> {
             name,
             desc,
             lua_type,
+            translated_type: None,
             source: span,
         })
     }
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct ImplementsTag<'a> {
+    pub name: Span<'a>,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+impl<'a> ImplementsTag<'a> {
+    pub fn parse(span: Span<'a>) -> Result<Self, Diagnostic> {
+        if span.contains(char::is_whitespace) {
+            return Err(span.diagnostic(
+                "Each interface needs its own tag, like \"@implements A\" and \"@implements B\"",
+            ));
+        }
+
+        Ok(Self {
+            name: span,
+            source: span,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn snapshot() {
+        assert_yaml_snapshot!(ImplementsTag::parse(Span::dummy("Clickable")), @r###"
+        ---
+        Ok:
+          name: Clickable
+        "###);
+
+        assert_yaml_snapshot!(ImplementsTag::parse(Span::dummy("Clickable Focusable")), @r###"
+        ---
+        Err:
+          text: "Each interface needs its own tag, like \"@implements A\" and \"@implements B\""
+          start: 0
+          len: 19
+          file_id: 0
+          additional_diagnostics: []
+        "###);
+    }
+}
@@ -0,0 +1,379 @@
+use std::{convert::TryFrom, iter::Peekable};
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+use super::{
+    emmylua::{description, split_type},
+    ClassTag, FieldTag, ParamTag, PrivateTag, ReturnTag, Tag,
+};
+
+/// Tags that only organize LDoc's own output, so they are left out of the docs. LDoc's
+/// `@within` groups entries into sections rather than naming their class.
+const IGNORED_TAGS: &[&str] = &[
+    "@alias",
+    "@author",
+    "@copyright",
+    "@export",
+    "@license",
+    "@raise",
+    "@release",
+    "@section",
+    "@see",
+    "@set",
+    "@static",
+    "@within",
+];
+
+/// Tags that are shorthand for `@tparam` with a type, like `@string name` for
+/// `@tparam string name`
+fn type_alias(tag_name: &str) -> Option<&'static str> {
+    match tag_name {
+        "@string" => Some("string"),
+        "@number" | "@int" => Some("number"),
+        "@bool" => Some("boolean"),
+        "@tab" => Some("table"),
+        "@func" => Some("(...any) -> ...any"),
+        "@thread" => Some("thread"),
+        _ => None,
+    }
+}
+
+/// The modifiers in brackets after a tag's name, like `@tparam[opt=1]`
+#[derive(Default)]
+struct Modifiers<'a> {
+    optional: bool,
+    default: Option<Span<'a>>,
+    lua_type: Option<Span<'a>>,
+}
+
+impl<'a> Modifiers<'a> {
+    fn parse(span: Span<'a>) -> Self {
+        let mut modifiers = Self::default();
+        let mut start = 0;
+
+        for piece in span.as_str().split(',') {
+            let modifier = span.slice(start, piece.len()).trim();
+            start += piece.len() + 1;
+
+            let mut pieces = modifier.splitn(2, "=");
+            let key = pieces.next().unwrap().trim();
+            let value = pieces.next().map(Span::trim);
+
+            match (key.as_str(), value) {
+                ("opt", default) => {
+                    modifiers.optional = true;
+                    modifiers.default = default.filter(|default| !default.is_empty());
+                }
+                ("type", lua_type) => modifiers.lua_type = lua_type,
+                _ => {}
+            }
+        }
+
+        modifiers
+    }
+}
+
+/// LDoc writes optional types like `?string`, which are documented as `string` on an optional
+/// param. A lone `?` is an optional param of any type.
+fn optional_type(lua_type: Span) -> (Span, bool) {
+    match lua_type.strip_prefix("?") {
+        Some(lua_type) if lua_type.is_empty() => (Span::dummy("any"), true),
+        Some(lua_type) => (lua_type, true),
+        None => (lua_type, false),
+    }
+}
+
+/// LDoc writes arrays like `{string,...}`, which are `{string}` in Luau
+fn translate_arrays(lua_type: &str) -> Option<String> {
+    let mut translated = String::with_capacity(lua_type.len());
+    let mut rest = lua_type;
+
+    while let Some(index) = rest.find(',') {
+        translated.push_str(&rest[..index]);
+
+        let after = rest[index + 1..].trim_start();
+        match after.strip_prefix("...").map(str::trim_start) {
+            Some(after) if after.starts_with('}') => rest = after,
+            _ => {
+                translated.push(',');
+                rest = &rest[index + 1..];
+            }
+        }
+    }
+
+    translated.push_str(rest);
+
+    (translated != lua_type).then_some(translated)
+}
+
+/// The type of a tag in Luau syntax, if it's written differently in LDoc. Params mark themselves
+/// as optional, but other tags have `?string|number` written as `(string | number)?`.
+fn translate_type(lua_type: Span, optional: bool) -> Option<String> {
+    let translated = translate_arrays(lua_type.as_str());
+
+    if !optional {
+        return translated;
+    }
+
+    let lua_type = translated.unwrap_or_else(|| lua_type.to_string());
+
+    Some(match lua_type.contains('|') {
+        true => format!("({})?", lua_type),
+        false => format!("{}?", lua_type),
+    })
+}
+
+/// Splits a name off the start of a tag's text, along with the description after it
+fn name_and_desc(span: Span) -> (Span, Span) {
+    let mut pieces = span.splitn(2, " ");
+    let name = pieces.next().unwrap().trim();
+    let desc = pieces
+        .next()
+        .map(|desc| description(desc.trim()))
+        .unwrap_or_else(|| Span::empty(span.file_id));
+
+    (name, desc)
+}
+
+fn param<'a>(
+    text: Span<'a>,
+    lua_type: Span<'a>,
+    rest: Span<'a>,
+    modifiers: Modifiers<'a>,
+) -> Result<Tag<'a>, Diagnostic> {
+    let (lua_type, optional) = optional_type(lua_type);
+    let (name, desc) = name_and_desc(rest);
+
+    if name.is_empty() {
+        return Err(text.diagnostic("Param name is required"));
+    }
+
+    Ok(Tag::Param(ParamTag {
+        name,
+        desc,
+        lua_type,
+        translated_type: translate_type(lua_type, false),
+        default: modifiers.default,
+        optional: optional || modifiers.optional,
+        source: text,
+    }))
+}
+
+/// Translates an LDoc tag into Moonwave tags. Tags that only matter to LDoc produce no tags, and
+/// anything else is parsed as a Moonwave tag.
+pub fn parse_ldoc_tag(text: Span<'_>) -> Vec<Result<Tag<'_>, Diagnostic>> {
+    let mut pieces = text.splitn(2, " ");
+    let head = pieces.next().unwrap().trim();
+    let rest = pieces
+        .next()
+        .map(Span::trim)
+        .unwrap_or_else(|| Span::empty(text.file_id));
+
+    let (tag_name, modifiers) = match head.as_str().find('[') {
+        Some(index) if head.ends_with(']') => (
+            head.slice(0, index),
+            Modifiers::parse(head.slice(index + 1, head.len() - index - 2)),
+        ),
+        _ => (head, Modifiers::default()),
+    };
+
+    // LDoc doesn't require types, but Moonwave does
+    let any = Span::dummy("any");
+
+    match tag_name.as_str() {
+        "@tparam" => {
+            let (lua_type, rest) = split_type(rest);
+            vec![param(text, lua_type, rest, modifiers)]
+        }
+        "@param" => {
+            let lua_type = modifiers.lua_type.unwrap_or(any);
+            vec![param(text, lua_type, rest, modifiers)]
+        }
+        alias if type_alias(alias).is_some() => {
+            let lua_type = Span::dummy(type_alias(alias).unwrap());
+            vec![param(text, lua_type, rest, modifiers)]
+        }
+        "@treturn" | "@return" => {
+            let (lua_type, desc) = match (tag_name.as_str(), modifiers.lua_type) {
+                ("@treturn", _) => split_type(rest),
+                (_, Some(lua_type)) => (lua_type, rest),
+                (_, None) => (any, rest),
+            };
+
+            let (lua_type, optional) = optional_type(lua_type);
+
+            vec![Ok(Tag::Return(ReturnTag {
+                desc: description(desc),
+                lua_type,
+                translated_type: translate_type(lua_type, optional),
+                source: text,
+            }))]
+        }
+        "@module" | "@classmod" | "@type" | "@table" => {
+            let (name, _) = name_and_desc(rest);
+
+            if name.is_empty() {
+                return vec![Err(text.diagnostic("Class name is required"))];
+            }
+
+            vec![Ok(Tag::Class(ClassTag { name, source: text }))]
+        }
+        "@field" | "@tfield" => {
+            let (lua_type, rest) = match (tag_name.as_str(), modifiers.lua_type) {
+                ("@tfield", _) => split_type(rest),
+                (_, Some(lua_type)) => (lua_type, rest),
+                (_, None) => (any, rest),
+            };
+            let (lua_type, optional) = optional_type(lua_type);
+            let (name, desc) = name_and_desc(rest);
+
+            vec![Ok(Tag::Field(FieldTag {
+                name,
+                desc,
+                lua_type,
+                translated_type: translate_type(lua_type, optional),
+                source: text,
+            }))]
+        }
+        "@local" => vec![Ok(Tag::Private(PrivateTag { source: text }))],
+        name if IGNORED_TAGS.contains(&name) => Vec::new(),
+        _ => vec![Tag::try_from(text)],
+    }
+}
+
+/// Whether a line of an LDoc comment starts a `@usage` tag
+pub fn is_usage_tag(line: &Span) -> bool {
+    line.as_str() == "@usage" || line.starts_with("@usage ")
+}
+
+/// Takes the code of a `@usage` tag, which is any code on the same line followed by every line
+/// up to the next tag
+pub fn take_usage_block<'a>(
+    line: Span<'a>,
+    lines: &mut Peekable<impl Iterator<Item = Span<'a>>>,
+) -> Vec<Span<'a>> {
+    let mut block: Vec<_> = line
+        .strip_prefix("@usage")
+        .map(Span::trim)
+        .filter(|code| !code.is_empty())
+        .into_iter()
+        .collect();
+
+    while let Some(line) = lines.next_if(|line| !line.starts_with('@')) {
+        block.push(line);
+    }
+
+    while block.last().is_some_and(|line| line.trim().is_empty()) {
+        block.pop();
+    }
+
+    block
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+    use crate::tags::luau_type;
+
+    fn parse(text: &str) -> Vec<Tag<'_>> {
+        parse_ldoc_tag(Span::dummy(text))
+            .into_iter()
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn tparam() {
+        assert_yaml_snapshot!(parse("@tparam string name the name to use"), @r###"
+        ---
+        - Param:
+            name: name
+            desc: the name to use
+            lua_type: string
+        "###);
+
+        let tags = parse("@tparam[opt=3] ?number retries how many times to try");
+
+        assert_yaml_snapshot!(tags, @r###"
+        ---
+        - Param:
+            name: retries
+            desc: how many times to try
+            lua_type: number
+            default: "3"
+        "###);
+
+        assert!(matches!(&tags[0], Tag::Param(param) if param.optional));
+    }
+
+    #[test]
+    fn type_alias() {
+        assert_yaml_snapshot!(parse("@int count the count"), @r###"
+        ---
+        - Param:
+            name: count
+            desc: the count
+            lua_type: number
+        "###);
+    }
+
+    #[test]
+    fn returns() {
+        assert_yaml_snapshot!(parse("@treturn {string,...} the names"), @r###"
+        ---
+        - Return:
+            desc: the names
+            lua_type: "{string,...}"
+        "###);
+
+        assert_yaml_snapshot!(parse("@return[type=boolean] whether it worked"), @r###"
+        ---
+        - Return:
+            desc: whether it worked
+            lua_type: boolean
+        "###);
+    }
+
+    #[test]
+    fn luau_types() {
+        let translated = |text| match &parse(text)[0] {
+            Tag::Param(param) => luau_type(param.lua_type, &param.translated_type),
+            Tag::Return(ret) => luau_type(ret.lua_type, &ret.translated_type),
+            Tag::Field(field) => luau_type(field.lua_type, &field.translated_type),
+            tag => panic!("unexpected tag {:?}", tag),
+        };
+
+        assert_eq!(translated("@tparam {string,...} names"), "{string}");
+        assert_eq!(
+            translated("@tparam {{number, ...}, ...} grid"),
+            "{{number}}"
+        );
+        assert_eq!(translated("@tparam ?string|number id"), "string|number");
+        assert_eq!(
+            translated("@treturn ?string|number the id"),
+            "(string|number)?"
+        );
+        assert_eq!(translated("@treturn ?{any,...} the values"), "{any}?");
+        assert_eq!(
+            translated("@tfield {string,number} pair a pair"),
+            "{string,number}"
+        );
+    }
+
+    #[test]
+    fn module() {
+        assert_yaml_snapshot!(parse("@module pl.List"), @r###"
+        ---
+        - Class:
+            name: pl.List
+        "###);
+    }
+
+    #[test]
+    fn ignored() {
+        assert!(parse("@author Steve").is_empty());
+        assert!(parse("@within Helpers").is_empty());
+    }
+}
@@ -27,6 +27,8 @@ define_marker_tag!(IgnoreTag);
 define_marker_tag!(YieldsTag);
 define_marker_tag!(ReadOnlyTag);
 define_marker_tag!(UnreleasedTag);
+define_marker_tag!(OverloadTag);
+define_marker_tag!(ConstructorTag);
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+use crate::{diagnostic::Diagnostic, span::Span};
+
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct ModuleTag<'a> {
+    pub name: Span<'a>,
+    #[serde(skip)]
+    pub source: Span<'a>,
+}
+
+impl<'a> ModuleTag<'a> {
+    pub fn parse(text: Span<'a>) -> Result<Self, Diagnostic> {
+        Ok(Self {
+            name: text,
+            source: text,
+        })
+    }
+}
@@ -7,6 +7,14 @@ pub struct ParamTag<'a> {
     pub name: Span<'a>,
     pub desc: Span<'a>,
     pub lua_type: Span<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Span<'a>>,
+    /// Set by formats that mark a param as optional apart from its name and type, like LDoc's
+    /// `@tparam[opt]`
+    #[serde(skip)]
+    pub optional: bool,
+    #[serde(skip)]
+    pub translated_type: Option<String>,
     #[serde(skip)]
     pub source: Span<'a>,
 }
@@ -27,15 +35,28 @@ impl<'a> ParamTag<'a> {
             return Err(span.diagnostic("Param name is required"));
         }
 
-        let lua_type = pieces
-            .next()
-            .map(|name| name.trim())
-            .unwrap_or_else(|| Span::dummy(""));
+        let (lua_type, default) = match pieces.next().map(|rest| rest.trim()) {
+            Some(rest) => {
+                let mut pieces = rest.splitn(2, "=");
+                let lua_type = pieces.next().unwrap().trim();
+                let default = pieces.next().map(|default| default.trim());
+
+                if default.is_some_and(|default| default.is_empty()) {
+                    return Err(span.diagnostic("Default value is required after ="));
+                }
+
+                (lua_type, default)
+            }
+            None => (Span::dummy(""), None),
+        };
 
         Ok(Self {
             name,
             desc,
             lua_type,
+            default,
+            optional: false,
+            translated_type: None,
             source: span,
         })
     }
@@ -83,4 +104,30 @@ mod test {
         lua_type: tasty
         "###);
     }
+
+    #[test]
+    fn default_value() {
+        let source = Span::dummy("retries number = 3 -- How many times to try again");
+        let value = ParamTag::parse(source).unwrap();
+        assert_yaml_snapshot!(value, @r###"
+        ---
+        name: retries
+        desc: How many times to try again
+        lua_type: number
+        default: "3"
+        "###);
+    }
+
+    #[test]
+    fn default_value_without_type() {
+        let source = Span::dummy("name = \"world\"");
+        let value = ParamTag::parse(source).unwrap();
+        assert_yaml_snapshot!(value, @r###"
+        ---
+        name: name
+        desc: ""
+        lua_type: ""
+        default: "\"world\""
+        "###);
+    }
 }
@@ -7,6 +7,8 @@ pub struct PropertyTag<'a> {
     pub name: Span<'a>,
     pub lua_type: Span<'a>,
     #[serde(skip)]
+    pub translated_type: Option<String>,
+    #[serde(skip)]
     pub source: Span<'a>,
 }
 
@@ -23,6 +25,7 @@ impl<'a> PropertyTag<'a> {
         Ok(Self {
             name,
             lua_type,
+            translated_type: None,
             source: span,
         })
     }
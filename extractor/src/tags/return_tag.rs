@@ -7,6 +7,8 @@ pub struct ReturnTag<'a> {
     pub desc: Span<'a>,
     pub lua_type: Span<'a>,
     #[serde(skip)]
+    pub translated_type: Option<String>,
+    #[serde(skip)]
     pub source: Span<'a>,
 }
 
@@ -27,6 +29,7 @@ impl<'a> ReturnTag<'a> {
         Ok(Self {
             desc,
             lua_type,
+            translated_type: None,
             source: span,
         })
     }
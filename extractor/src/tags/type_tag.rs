@@ -12,13 +12,23 @@ pub struct TypeTag<'a> {
 
 impl<'a> TypeTag<'a> {
     pub fn parse(span: Span<'a>) -> Result<Self, Diagnostic> {
-        let mut pieces = span.splitn(2, " ");
-        let name = pieces.next().unwrap().trim();
+        let text = span.as_str();
+        let name_end = text.find(' ').unwrap_or(text.len());
 
-        let lua_type = pieces
-            .next()
-            .map(Span::trim)
-            .ok_or_else(|| span.diagnostic("Property type is required"))?;
+        // The generics a type declares, like `Map<Key, Value>`, are part of its name
+        let name_end = match text[..name_end].find('<') {
+            Some(open) => text[open..]
+                .find('>')
+                .map_or(name_end, |close| open + close + 1),
+            None => name_end,
+        };
+
+        let name = span.slice(0, name_end).trim();
+        let lua_type = span.slice(name_end, text.len() - name_end).trim();
+
+        if lua_type.is_empty() {
+            return Err(span.diagnostic("Property type is required"));
+        }
 
         Ok(Self {
             name,
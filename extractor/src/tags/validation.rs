@@ -8,12 +8,49 @@ static MUTUALLY_EXCLUSIVE: &[(TagType, TagType)] = &[
     (TagType::Property, TagType::Function),
     (TagType::Property, TagType::Class),
     (TagType::Function, TagType::Class),
-    // Classes aren't within other classes
+    (TagType::Event, TagType::Property),
+    (TagType::Event, TagType::Function),
+    (TagType::Event, TagType::Class),
+    (TagType::Event, TagType::Type),
+    (TagType::Event, TagType::Module),
+    // Callbacks are implemented by users rather than called, so they're a kind of their own
+    (TagType::Callback, TagType::Function),
+    (TagType::Callback, TagType::Property),
+    (TagType::Callback, TagType::Class),
+    (TagType::Callback, TagType::Type),
+    (TagType::Callback, TagType::Event),
+    (TagType::Callback, TagType::Module),
+    // Only functions can be constructors, and a callback is never called to construct anything
+    (TagType::Constructor, TagType::Property),
+    (TagType::Constructor, TagType::Class),
+    (TagType::Constructor, TagType::Type),
+    (TagType::Constructor, TagType::Event),
+    (TagType::Constructor, TagType::Module),
+    (TagType::Constructor, TagType::Callback),
+    // Modules are a kind of their own
+    (TagType::Module, TagType::Class),
+    (TagType::Module, TagType::Property),
+    (TagType::Module, TagType::Function),
+    (TagType::Module, TagType::Type),
+    // Classes and modules aren't within other classes
     (TagType::Class, TagType::Within),
+    (TagType::Module, TagType::Within),
+    // Modules aren't instantiated, so they have no __index, parent class or interfaces
+    (TagType::Module, TagType::Index),
+    (TagType::Module, TagType::Extends),
+    (TagType::Module, TagType::Implements),
     // __index only works on classes
     (TagType::Index, TagType::Property),
     (TagType::Index, TagType::Function),
     (TagType::Index, TagType::Type),
+    // Only classes can extend other classes
+    (TagType::Extends, TagType::Property),
+    (TagType::Extends, TagType::Function),
+    (TagType::Extends, TagType::Type),
+    // Only classes can implement interfaces
+    (TagType::Implements, TagType::Property),
+    (TagType::Implements, TagType::Function),
+    (TagType::Implements, TagType::Type),
     // Param doesn't work with kinds other than function
     (TagType::Param, TagType::Property),
     (TagType::Param, TagType::Class),
@@ -22,6 +59,7 @@ static MUTUALLY_EXCLUSIVE: &[(TagType, TagType)] = &[
     (TagType::Return, TagType::Property),
     (TagType::Return, TagType::Class),
     (TagType::Return, TagType::Type),
+    (TagType::Return, TagType::Event),
     // Field is exclusive with function
     // (TagType::Field, TagType::Function),
     // Properties can't error or yield
@@ -30,11 +68,19 @@ static MUTUALLY_EXCLUSIVE: &[(TagType, TagType)] = &[
     // Classes can't error or yield
     (TagType::Error, TagType::Class),
     (TagType::Yields, TagType::Class),
+    // Events are fired, not called, so they can't error or yield either
+    (TagType::Error, TagType::Event),
+    (TagType::Yields, TagType::Event),
     // Can't be unreleased and released at the same time
     (TagType::Unreleased, TagType::Since),
     (TagType::Unreleased, TagType::Deprecated),
     // Readonly doesn't make sense on a function
     (TagType::Function, TagType::ReadOnly),
+    // Only functions can be overloaded
+    (TagType::Overload, TagType::Property),
+    (TagType::Overload, TagType::Class),
+    (TagType::Overload, TagType::Type),
+    (TagType::Overload, TagType::Event),
 ];
 
 static DEPENDENT_TAGS: &[(TagType, TagType)] = &[]; // Was used in the past
@@ -46,6 +92,8 @@ static ALLOW_MULTIPLE: &[TagType] = &[
     TagType::Field,
     TagType::Error,
     TagType::External,
+    TagType::Example,
+    TagType::Implements,
 ];
 
 fn build_diagnostic(
@@ -81,12 +129,19 @@ pub fn validate_global_tags(tags: &[Tag]) -> Vec<Diagnostic> {
         let name = match tag {
             Tag::External(external_tag) => external_tag.name,
             Tag::Class(class_tag) => class_tag.name,
+            Tag::Module(module_tag) => module_tag.name,
             _ => continue,
         }
         .to_string();
 
+        // Classes and modules share a namespace
+        let tag_type = match tag.tag_type() {
+            TagType::Module => TagType::Class,
+            tag_type => tag_type,
+        };
+
         let entry = name_occurrences
-            .entry((tag.tag_type(), name))
+            .entry((tag_type, name))
             .or_insert((0usize, vec![]));
         entry.0 += 1;
         entry.1.push(tag.clone());
@@ -97,9 +152,14 @@ pub fn validate_global_tags(tags: &[Tag]) -> Vec<Diagnostic> {
             continue;
         }
 
+        let tag_types: &[&TagType] = match tag_type {
+            TagType::Class => &[&TagType::Class, &TagType::Module],
+            _ => &[&tag_type],
+        };
+
         diagnostics.push(build_diagnostic(
             &tags,
-            &[&tag_type],
+            tag_types,
             "This tag cannot be used multiple times with the same name.",
             "Appears here",
         ))
@@ -0,0 +1,507 @@
+use std::fmt;
+
+use full_moon::{
+    ast::{
+        luau::{IndexedTypeInfo, TypeArgument, TypeFieldKey, TypeInfo},
+        Stmt,
+    },
+    node::Node,
+    tokenizer::TokenReference,
+    LuaVersion,
+};
+use serde::Serialize;
+
+/// A Luau type parsed from a `lua_type` string, so that renderers don't need to tokenize types
+/// themselves
+#[derive(Debug, PartialEq, Serialize, Clone)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TypeAst {
+    /// A named type, like `string`, `Promise`, or `Module.Type`
+    Reference { name: String },
+    /// A named type with generic arguments, like `Promise<T>`
+    Generic {
+        name: String,
+        generics: Vec<TypeAst>,
+    },
+    /// A string or boolean singleton, like `"foo"` or `true`
+    Literal { value: String },
+    /// `T?`
+    Optional { inner: Box<TypeAst> },
+    /// `A | B`
+    Union { types: Vec<TypeAst> },
+    /// `A & B`
+    Intersection { types: Vec<TypeAst> },
+    /// `(A, B) -> C`
+    Function {
+        params: Vec<TypeAstParam>,
+        returns: Vec<TypeAst>,
+    },
+    /// `{ key: T, [K]: V }`
+    Table { fields: Vec<TypeAstField> },
+    /// `{ T }`
+    Array { element: Box<TypeAst> },
+    /// `(A, B)`
+    Tuple { types: Vec<TypeAst> },
+    /// `typeof(expression)`
+    Typeof { expression: String },
+    /// `...T`
+    Variadic { inner: Box<TypeAst> },
+    /// `T...`
+    GenericPack { name: String },
+}
+
+/// A parameter of a function type
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct TypeAstParam {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "type")]
+    pub lua_type: TypeAst,
+}
+
+/// A field of a table type. Exactly one of `name` and `indexer` is set.
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct TypeAstField {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexer: Option<TypeAst>,
+    pub value: TypeAst,
+}
+
+/// `function` is commonly used as a type, but it's a keyword, so it's swapped out for this name
+/// while parsing
+const FUNCTION_PLACEHOLDER: &str = "__MoonwaveFunction";
+
+fn token_text(token: &TokenReference) -> String {
+    match token.token().to_string() {
+        text if text == FUNCTION_PLACEHOLDER => "function".to_owned(),
+        text => text,
+    }
+}
+
+fn replace_function_keyword(lua_type: &str) -> String {
+    let is_identifier = |char: char| char.is_alphanumeric() || char == '_';
+    let mut result = String::with_capacity(lua_type.len());
+    let mut rest = lua_type;
+
+    while let Some(index) = rest.find("function") {
+        let (before, after) = (&rest[..index], &rest[index + "function".len()..]);
+
+        let standalone = !result
+            .chars()
+            .chain(before.chars())
+            .last()
+            .is_some_and(is_identifier)
+            && !after.starts_with(is_identifier);
+
+        result.push_str(before);
+        result.push_str(if standalone {
+            FUNCTION_PLACEHOLDER
+        } else {
+            "function"
+        });
+        rest = after;
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Why a type string couldn't be parsed, and where in it the problem is
+#[derive(Debug, PartialEq, Serialize, Clone)]
+pub struct TypeParseError {
+    pub message: String,
+    /// The byte offset of the offending text within the type string
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl TypeParseError {
+    fn new(message: impl Into<String>, offset: usize, len: usize) -> Self {
+        Self {
+            message: message.into(),
+            offset,
+            len,
+        }
+    }
+}
+
+impl fmt::Display for TypeParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(&self.message)
+    }
+}
+
+/// Types are parsed as the value of this type declaration
+const TYPE_PREFIX: &str = "type __MoonwaveType = ";
+
+/// Maps an offset within the parsed code back to one within the type string, undoing the prefix
+/// and any `function` keywords that were replaced
+fn original_offset(code: &str, offset: usize) -> usize {
+    let offset = offset.clamp(TYPE_PREFIX.len(), code.len());
+    let replaced = code[TYPE_PREFIX.len()..offset]
+        .matches(FUNCTION_PLACEHOLDER)
+        .count();
+
+    offset - TYPE_PREFIX.len() - replaced * (FUNCTION_PLACEHOLDER.len() - "function".len())
+}
+
+/// Whether a parse error is about the statements around the type, rather than the type
+fn is_statement_error(message: &str) -> bool {
+    message.contains("statement")
+        || message == "expected type name"
+        || message == "expected `=` after type name"
+}
+
+impl TypeAst {
+    /// Parses a type string with full_moon's Luau type parser. A leading `...` is allowed so that
+    /// variadic parameter and return types can be parsed too.
+    pub fn parse(lua_type: &str) -> Result<Self, TypeParseError> {
+        let leading = lua_type.len() - lua_type.trim_start().len();
+        let lua_type = lua_type.trim();
+
+        if let Some(inner) = lua_type.strip_prefix("...") {
+            return Self::parse(inner)
+                .map(|inner| TypeAst::Variadic {
+                    inner: Box::new(inner),
+                })
+                .map_err(|error| TypeParseError {
+                    offset: error.offset + leading + "...".len(),
+                    ..error
+                });
+        }
+
+        Self::parse_type(lua_type).map_err(|error| TypeParseError {
+            offset: error.offset + leading,
+            ..error
+        })
+    }
+
+    fn parse_type(lua_type: &str) -> Result<Self, TypeParseError> {
+        if lua_type.is_empty() {
+            return Err(TypeParseError::new("expected a type", 0, 0));
+        }
+
+        let code = format!("{}{}", TYPE_PREFIX, replace_function_keyword(lua_type));
+        let result = full_moon::parse_fallible(&code, LuaVersion::luau());
+        let ast = result.ast();
+
+        let mut stmts = ast.nodes().stmts();
+        let declaration = match stmts.next() {
+            Some(Stmt::TypeDeclaration(declaration)) => Some(declaration),
+            _ => None,
+        };
+
+        // Whatever follows the end of the type, if the type itself could be parsed. Tokens aren't
+        // visited in the order they're written, so the type ends at the furthest one.
+        let type_end = declaration
+            .and_then(|declaration| {
+                let tokens = declaration.type_definition().tokens();
+                tokens
+                    .map(|token| token.token().end_position().bytes())
+                    .max()
+            })
+            .map(|end| original_offset(&code, end));
+        let trailing = type_end
+            .map(|end| (end, lua_type[end..].trim()))
+            .filter(|(_, rest)| !rest.is_empty());
+
+        let first_error = result.errors().first();
+
+        // Text after a complete type is parsed as more statements, so errors about statements mean
+        // that the type itself was fine
+        let after_type = trailing
+            .filter(|_| first_error.is_none_or(|error| is_statement_error(&error.error_message())));
+
+        if let Some((end, rest)) = after_type {
+            let offset = end + lua_type[end..].find(rest).unwrap_or(0);
+
+            return Err(TypeParseError::new(
+                "unexpected text after type",
+                offset,
+                rest.len(),
+            ));
+        }
+
+        if let Some(error) = first_error {
+            let (start, end) = error.range();
+            let offset = original_offset(&code, start.bytes());
+            let len = original_offset(&code, end.bytes()).saturating_sub(offset);
+
+            // Errors about statements come from the declaration that wraps the type
+            let message = match is_statement_error(&error.error_message()) {
+                true => "expected a type".into(),
+                false => error.error_message(),
+            };
+
+            // Errors at the end of the code point at the last character of the type instead
+            let last = lua_type.char_indices().last().map_or(0, |(index, _)| index);
+            let offset = offset.min(last);
+            let len = len.clamp(1, lua_type.len() - offset);
+
+            return Err(TypeParseError::new(message, offset, len));
+        }
+
+        match (declaration, stmts.next(), ast.nodes().last_stmt()) {
+            (Some(declaration), None, None) => Ok(Self::from(declaration.type_definition())),
+            _ => Err(TypeParseError::new("expected a type", 0, lua_type.len())),
+        }
+    }
+}
+
+impl From<&TypeArgument> for TypeAstParam {
+    fn from(argument: &TypeArgument) -> Self {
+        Self {
+            name: argument.name().map(|(name, _)| token_text(name)),
+            lua_type: argument.type_info().into(),
+        }
+    }
+}
+
+fn returns_from_type(info: &TypeInfo) -> Vec<TypeAst> {
+    match info {
+        TypeInfo::Tuple { types, .. } => types.iter().map(TypeAst::from).collect(),
+        _ => vec![info.into()],
+    }
+}
+
+impl From<&TypeInfo> for TypeAst {
+    fn from(info: &TypeInfo) -> Self {
+        match info {
+            TypeInfo::Basic(token) => TypeAst::Reference {
+                name: token_text(token),
+            },
+            TypeInfo::String(token) | TypeInfo::Boolean(token) => TypeAst::Literal {
+                value: token_text(token),
+            },
+            TypeInfo::Generic { base, generics, .. } => TypeAst::Generic {
+                name: token_text(base),
+                generics: generics.iter().map(TypeAst::from).collect(),
+            },
+            TypeInfo::Module {
+                module, type_info, ..
+            } => match type_info.as_ref() {
+                IndexedTypeInfo::Basic(token) => TypeAst::Reference {
+                    name: format!("{}.{}", token_text(module), token_text(token)),
+                },
+                IndexedTypeInfo::Generic { base, generics, .. } => TypeAst::Generic {
+                    name: format!("{}.{}", token_text(module), token_text(base)),
+                    generics: generics.iter().map(TypeAst::from).collect(),
+                },
+                _ => TypeAst::Reference {
+                    name: info.to_string().trim().to_owned(),
+                },
+            },
+            TypeInfo::Optional { base, .. } => TypeAst::Optional {
+                inner: Box::new(base.as_ref().into()),
+            },
+            TypeInfo::Union(union) => TypeAst::Union {
+                types: union.types().iter().map(TypeAst::from).collect(),
+            },
+            TypeInfo::Intersection(intersection) => TypeAst::Intersection {
+                types: intersection.types().iter().map(TypeAst::from).collect(),
+            },
+            TypeInfo::Callback {
+                arguments,
+                return_type,
+                ..
+            } => TypeAst::Function {
+                params: arguments.iter().map(TypeAstParam::from).collect(),
+                returns: returns_from_type(return_type),
+            },
+            TypeInfo::Table { fields, .. } => TypeAst::Table {
+                fields: fields
+                    .iter()
+                    .map(|field| match field.key() {
+                        TypeFieldKey::Name(name) => TypeAstField {
+                            name: Some(token_text(name)),
+                            indexer: None,
+                            value: field.value().into(),
+                        },
+                        key => TypeAstField {
+                            name: None,
+                            indexer: match key {
+                                TypeFieldKey::IndexSignature { inner, .. } => Some(inner.into()),
+                                _ => None,
+                            },
+                            value: field.value().into(),
+                        },
+                    })
+                    .collect(),
+            },
+            TypeInfo::Array { type_info, .. } => TypeAst::Array {
+                element: Box::new(type_info.as_ref().into()),
+            },
+            TypeInfo::Tuple { types, .. } => {
+                if types.len() == 1 {
+                    // Parentheses used for grouping, like `(A | B)?`
+                    types.iter().next().unwrap().into()
+                } else {
+                    TypeAst::Tuple {
+                        types: types.iter().map(TypeAst::from).collect(),
+                    }
+                }
+            }
+            TypeInfo::Typeof { inner, .. } => TypeAst::Typeof {
+                expression: inner.to_string().trim().to_owned(),
+            },
+            TypeInfo::Variadic { type_info, .. } => TypeAst::Variadic {
+                inner: Box::new(type_info.as_ref().into()),
+            },
+            TypeInfo::VariadicPack { name, .. } => TypeAst::Variadic {
+                inner: Box::new(TypeAst::Reference {
+                    name: token_text(name),
+                }),
+            },
+            TypeInfo::GenericPack { name, .. } => TypeAst::GenericPack {
+                name: token_text(name),
+            },
+            _ => TypeAst::Reference {
+                name: info.to_string().trim().to_owned(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use insta::assert_yaml_snapshot;
+
+    use super::*;
+
+    #[test]
+    fn reference() {
+        assert_yaml_snapshot!(TypeAst::parse("Module.Type"), @r###"
+        ---
+        Ok:
+          kind: reference
+          name: Module.Type
+        "###);
+    }
+
+    #[test]
+    fn union_and_optional() {
+        assert_yaml_snapshot!(TypeAst::parse("string | (number | boolean)?"), @r###"
+        ---
+        Ok:
+          kind: union
+          types:
+            - kind: reference
+              name: string
+            - kind: optional
+              inner:
+                kind: union
+                types:
+                  - kind: reference
+                    name: number
+                  - kind: reference
+                    name: boolean
+        "###);
+    }
+
+    #[test]
+    fn function() {
+        assert_yaml_snapshot!(TypeAst::parse("(player: Player, ...string) -> (boolean, Promise<T>)"), @r###"
+        ---
+        Ok:
+          kind: function
+          params:
+            - name: player
+              type:
+                kind: reference
+                name: Player
+            - type:
+                kind: variadic
+                inner:
+                  kind: reference
+                  name: string
+          returns:
+            - kind: reference
+              name: boolean
+            - kind: generic
+              name: Promise
+              generics:
+                - kind: reference
+                  name: T
+        "###);
+    }
+
+    #[test]
+    fn table() {
+        assert_yaml_snapshot!(TypeAst::parse("{ name: \"literal\", [string]: { number } }"), @r###"
+        ---
+        Ok:
+          kind: table
+          fields:
+            - name: name
+              value:
+                kind: literal
+                value: "\"literal\""
+            - indexer:
+                kind: reference
+                name: string
+              value:
+                kind: array
+                element:
+                  kind: reference
+                  name: number
+        "###);
+    }
+
+    #[test]
+    fn variadic() {
+        assert_yaml_snapshot!(TypeAst::parse("...any"), @r###"
+        ---
+        Ok:
+          kind: variadic
+          inner:
+            kind: reference
+            name: any
+        "###);
+    }
+
+    #[test]
+    fn function_keyword() {
+        assert_yaml_snapshot!(TypeAst::parse("{ [string]: function }?"), @r###"
+        ---
+        Ok:
+          kind: optional
+          inner:
+            kind: table
+            fields:
+              - indexer:
+                  kind: reference
+                  name: string
+                value:
+                  kind: reference
+                  name: function
+        "###);
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(TypeAst::parse("Roact.Element<{ oh_no: string -> coroutine }>").is_err());
+        assert!(TypeAst::parse("number foo").is_err());
+    }
+
+    #[test]
+    fn text_after_type() {
+        assert_eq!(
+            TypeAst::parse(" string[]"),
+            Err(TypeParseError::new("unexpected text after type", 7, 2))
+        );
+        assert_eq!(
+            TypeAst::parse("function -- a callback"),
+            Err(TypeParseError::new("unexpected text after type", 9, 13))
+        );
+    }
+
+    #[test]
+    fn missing_type() {
+        assert_eq!(
+            TypeAst::parse("...").map_err(|error| error.message),
+            Err("expected a type".to_owned())
+        );
+    }
+}
@@ -0,0 +1,21 @@
+--[=[
+	@class Timer
+]=]
+local Timer = {}
+
+--[=[
+	@constructor
+]=]
+function Timer:create() end
+
+--[=[
+	@callback OnTick
+	@function onTick
+	@within Timer
+]=]
+
+--[=[
+	@prop running boolean
+	@within Timer
+	@constructor
+]=]
@@ -0,0 +1,21 @@
+--[=[
+	@class Player
+]=]
+
+--[=[
+	@event Changed Signal<string>
+	@within Player
+]=]
+
+--[=[
+	@event Died
+	@within Player
+	@return boolean
+]=]
+
+--[=[
+	@event Spawned
+	@within Player
+	@param position
+	@yields
+]=]
@@ -0,0 +1,40 @@
+--[=[
+	@interface Clickable
+	@within Button
+	.label string
+	.onClick (self: Button) -> ()
+	.size { x: number, y: number }
+	.draw (self: Button, scale: number) -> boolean
+]=]
+
+--[=[
+	@type Point { x: number, y: number }
+	@within Button
+]=]
+
+--[=[
+	@class Button
+	@implements Clickable
+	@implements Clikable
+	@implements Point
+]=]
+local Button = {}
+
+--[=[
+	@prop label number
+	@within Button
+]=]
+
+--[=[
+	Resizes the button.
+]=]
+function Button:size() end
+
+--[=[
+	Draws the button.
+]=]
+function Button:draw(scale: string): string
+	return ""
+end
+
+return Button
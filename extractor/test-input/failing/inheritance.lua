@@ -0,0 +1,19 @@
+--[=[
+	@class Button
+	@extends BaseComponnet
+]=]
+
+--[=[
+	@class Chicken
+	@extends Egg
+]=]
+
+--[=[
+	@class Egg
+	@extends Chicken
+]=]
+
+--[=[
+	@class Ouroboros
+	@extends Ouroboros
+]=]
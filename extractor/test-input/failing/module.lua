@@ -0,0 +1,11 @@
+--[=[
+	@module Helpers
+]=]
+local Helpers = {}
+
+--[=[
+	Not exported, so it has nowhere to go.
+]=]
+local function private() end
+
+return Helpers
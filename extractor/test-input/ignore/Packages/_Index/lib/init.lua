@@ -0,0 +1,6 @@
+--[=[
+	@class Main
+
+	A vendored copy of a dependency.
+]=]
+return {}
@@ -0,0 +1,6 @@
+--[=[
+	@class Main
+
+	The only class that's documented.
+]=]
+return {}
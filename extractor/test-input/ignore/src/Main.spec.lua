@@ -0,0 +1,6 @@
+--[=[
+	@class Main
+
+	A test that would conflict with the real class.
+]=]
+return {}
@@ -0,0 +1,63 @@
+--[=[
+	@class Shop
+]=]
+local Shop = {}
+
+--[=[
+	@type Price number
+	@within Shop
+]=]
+export type Price = number
+
+--[=[
+	Tags that agree with the annotation, written differently.
+
+	@param item {  name: string, price: Price } -- The item to sell
+	@param discount number | nil -- How much to take off
+	@return Shop.Price
+]=]
+function Shop.sell(item: { price: number, name: string }, discount: number?): Price
+	return item.price - (discount or 0)
+end
+
+--[=[
+	Tags that disagree with the annotation.
+
+	@param name number -- The name of the item
+	@param count number -- How many to buy
+	@return boolean -- Whether it was bought
+	@return string -- Why it wasn't bought
+]=]
+function Shop.buy(name: string, count: number): boolean
+	return true
+end
+
+--[=[
+	@param callback (number) -> string -- Called with each price
+	@return Price -- The wrong return type
+]=]
+function Shop.each(callback: (price: number) -> string): boolean
+	return true
+end
+
+--[=[
+	@class Bank
+]=]
+local Bank = {}
+
+--[=[
+	A different type with the same name, which doesn't change what `Price` means in `Shop`.
+
+	@type Price string
+	@within Bank
+]=]
+
+--[=[
+	@param amount Price -- How much to deposit, written out
+	@return Shop.Price -- How much was deposited
+]=]
+function Bank.deposit(amount: string): number
+	return tonumber(amount) or 0
+end
+
+return Shop
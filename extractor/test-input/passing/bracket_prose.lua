@@ -0,0 +1,23 @@
+--[=[
+	@class Checklist
+
+	Tracks what's left to do. See [Note] below before using it.
+
+	- [x] Adding items
+	- [ ] Removing items
+	1. [X] Saving to disk
+
+	Values t[i] are read in order, and items[1] is always the oldest.
+
+	[Note]: Lists aren't saved automatically.
+]=]
+local Checklist = {}
+
+--[=[
+	Marks an item as done [if it exists].
+
+	@param name string -- The item's name [case sensitive]
+]=]
+function Checklist:finish(name) end
+
+return Checklist
@@ -0,0 +1,66 @@
+--[=[
+	@class Inventory
+
+	Keeps track of the items a player is holding, which can be shown in a [TextLabel].
+]=]
+local Inventory = {}
+Inventory.__index = Inventory
+
+--[=[
+	@type Item { name: string, count: number }
+	@within Inventory
+]=]
+
+--[=[
+	@type Lookup<Key, Value> { [Key]: Value, fallback: Valeu }
+	@within Inventory
+]=]
+
+--[=[
+	@interface Slot
+	@within Inventory
+	.item Item?
+	.position Vectro2
+]=]
+
+--[=[
+	@prop owner Player
+	@within Inventory
+]=]
+
+--[=[
+	@prop display Frame
+	@within Inventory
+]=]
+
+--[=[
+	Adds an item to the inventory.
+
+	@external Signal https://sleitnick.github.io/RbxUtil/api/Signal
+	@param item Inventory.Item -- The item to add
+	@param slot Slott -- The slot to put it in
+	@param callback (T) -> Signal<Itme>
+	@return boolean | Promise<Status>
+]=]
+function Inventory:add(item, slot, callback)
+	return true
+end
+
+--[=[
+	Groups the items by a key.
+]=]
+function Inventory:group<Key>(getKey: (Item) -> Key): Lookup<Key, { Item }>
+	return {}
+end
+
+--[=[
+	Finds an item by its name.
+
+	@param name String
+	@return Item? -- The item, if the inventory holds it
+]=]
+function Inventory:find(name)
+	return nil
+end
+
+return Inventory
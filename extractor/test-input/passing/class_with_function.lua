@@ -19,3 +19,18 @@
 	@deprecated v1.2 -- This is no good.
 	@return string -- AAAAAAAAAAAAAAAAAAAAAAAAAA
 ]=]
+
+--[=[
+	@function g
+	@within aclass
+	@param count the count
+	@return string
+]=]
+
+--[=[
+	@function g
+	@within aclass
+	@overload
+	@param name string
+	@return string
+]=]
@@ -0,0 +1,56 @@
+--[=[
+	@class Counter
+
+	Counts things.
+
+	```lua
+	local counter = Counter.new()
+	counter:increment()
+	print(counter.count)
+	```
+
+	This example is out of date, and no longer parses:
+
+	```lua
+	local counter = Counter.new()
+	counter:increment(
+	print(counter.count)
+	```
+
+	Blocks in other languages, and blocks tagged `ignore`, are skipped:
+
+	```lua ignore
+	counter:increment(1 2 3)
+	```
+
+	```bash
+	moonwave build --publish
+	```
+]=]
+local Counter = {}
+
+--[=[
+	Increments the counter.
+
+	~~~luau
+	counter:increment(by: number)
+	~~~
+]=]
+function Counter:increment() end
+
+--[=[
+	Resets the counter to zero.
+
+	@example Pseudocode
+	```lua ignore
+	counter:reset(to zero)
+	```
+
+	@example Broken
+	```lua
+	counter:reset(
+	```
+]=]
+function Counter:reset() end
+
+return Counter
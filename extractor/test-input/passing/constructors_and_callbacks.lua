@@ -0,0 +1,44 @@
+--[=[
+	@class Timer
+]=]
+local Timer = {}
+Timer.__index = Timer
+
+--[=[
+	Creates a new timer.
+]=]
+function Timer.new(duration: number): Timer
+	return setmetatable({ duration = duration }, Timer)
+end
+
+--[=[
+	@constructor
+
+	Creates a timer that has already started.
+]=]
+function Timer.started(duration: number): Timer
+	return Timer.new(duration)
+end
+
+--[=[
+	Creates a new timer, but named `new` while returning something else.
+]=]
+function Timer.fromString(text: string): Timer?
+	return nil
+end
+
+--[=[
+	@callback OnTick
+	@within Timer
+	@param elapsed number -- How long the timer has been running for
+	@return boolean -- Whether the timer should keep running
+
+	Called on every tick of the timer.
+]=]
+
+--[=[
+	Starts the timer.
+]=]
+function Timer:start() end
+
+return Timer
@@ -0,0 +1,48 @@
+--[=[
+	@class Timer
+
+	Calls a function after a delay. See [Timer.new] to create one, and [`Timer:start`] to start
+	it. Timers are often used with [Vector3] values, and the [TimerState] type.
+
+	Regular [markdown links](https://example.com) and [reference links][ref] are left alone, as
+	is text like `[NotALink]` in inline code.
+
+	```lua
+	local timer = Timer.new(5) -- [Ignored] inside code blocks
+	```
+
+	[ref]: https://example.com
+]=]
+local Timer = {}
+Timer.__index = Timer
+
+--[=[
+	@type TimerState "running" | "stopped"
+	@within Timer
+]=]
+
+--[=[
+	Creates a timer that is stopped. Use [Timer:strat] to start it.
+
+	@param seconds number -- How long to wait, see [Timer.duration]
+	@return Timer -- The new [`Timr`]
+]=]
+function Timer.new(seconds)
+	return setmetatable({ duration = seconds }, Timer)
+end
+
+--[=[
+	@prop duration number
+	@within Timer
+
+	How long the timer waits, which the [Timer:start] method counts down from.
+]=]
+
+--[=[
+	Starts the timer. Errors if it's [Missing.class] or [Unknown].
+
+	@error "AlreadyRunning" -- When the [TimerState] is already "running"
+]=]
+function Timer:start() end
+
+return Timer
@@ -0,0 +1,51 @@
+--[=[
+	@class Queue
+
+	A queue for Lua 5.4.
+
+	```lua
+	local queue <close> = Queue.new()
+	queue:push(7 // 2)
+	```
+]=]
+local Queue = {}
+Queue.__index = Queue
+
+--[=[
+	@return Queue
+]=]
+function Queue.new()
+	local self <const> = setmetatable({ first = 1, last = 0 }, Queue)
+
+	return self
+end
+
+--[=[
+	Pushes every value, skipping nils.
+
+	@param ... any
+]=]
+function Queue:push(...)
+	for i = 1, select("#", ...) do
+		local value = select(i, ...)
+
+		if value == nil then
+			goto continue
+		end
+
+		self.last = self.last + 1
+		self[self.last] = value
+
+		::continue::
+	end
+end
+
+--[=[
+	@param count integer
+	@return integer -- The number of pages
+]=]
+function Queue:pages(count)
+	return (self.last - self.first + 1) // count
+end
+
+return Queue
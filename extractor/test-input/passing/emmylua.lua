@@ -0,0 +1,44 @@
+---@meta
+
+--- A good dog.
+---@class Dog : Animal
+---@field name string The dog's name
+---@field private hunger number
+---@field toys string[] What it plays with
+---@field [string] any
+local Dog = {}
+
+--- The base animal.
+---@class Animal
+local Animal = {}
+
+---Creates a dog.
+---@param name string The name
+---@param age? integer # How old it is
+---@return Dog
+function Dog.new(name, age)
+	return setmetatable({ name = name }, Dog)
+end
+
+--- Barks at something.
+---@async
+---@param target string|nil What to bark at
+---@return boolean barked # Whether it barked
+---@return string
+function Dog:bark(target)
+	return true, "woof"
+end
+
+--- Finds dogs by name.
+---@param names string[] The names to look for
+---@param filter? fun(dog: Dog): boolean
+---@return table<string, Dog> found # The dogs, by name
+---@return string[] missing
+function Dog.find(names, filter)
+	return {}, {}
+end
+
+---@type table<string, Dog>
+local cache = {}
+
+return Dog
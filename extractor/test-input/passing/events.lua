@@ -0,0 +1,24 @@
+--[=[
+	@class Player
+]=]
+local Player = {}
+
+--[=[
+	@event Changed
+	@within Player
+	@param property string -- The name of the property that changed
+	@param value any
+	@server
+	@since 1.2.0
+
+	Fires when a property of the player changes.
+]=]
+
+--[=[
+	@signal Player.Died
+	@client
+
+	Fires when the player's [Player.Changed] event reports zero health.
+]=]
+
+return Player
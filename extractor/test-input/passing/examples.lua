@@ -0,0 +1,54 @@
+--[=[
+	@class Stack
+
+	A last in, first out collection.
+
+	@example Creating a stack
+	```lua
+	local stack = Stack.new()
+	stack:push(1)
+	```
+]=]
+local Stack = {}
+Stack.__index = Stack
+
+--[=[
+	Pushes a value onto the top of the stack.
+
+	@param value any -- The value to push
+	@example
+		local stack = Stack.new()
+
+		for i = 1, 10 do
+			stack:push(i)
+		end
+
+	@example Chaining
+		stack:push(1):push(2
+]=]
+function Stack:push(value)
+	table.insert(self, value)
+	return self
+end
+
+--- Removes the value on top of the stack.
+--- @example Popping
+---     local value = stack:pop()
+--- @return any -- The value that was removed
+function Stack:pop()
+	return table.remove(self)
+end
+
+--[=[
+	@prop size number
+	@within Stack
+
+	How many values are in the stack.
+
+	@example From the shell
+	```bash
+	echo "this is not Lua, so it isn't checked"
+	```
+]=]
+
+return Stack
@@ -0,0 +1,41 @@
+--[=[
+	@interface Clickable
+	@within Button
+	.label string -- The text shown on the button
+	.onClick (self: Button) -> () -- Called when the button is clicked
+]=]
+
+--[=[
+	@interface Hoverable
+	@within BaseComponent
+	.hovered boolean
+]=]
+
+--[=[
+	@class BaseComponent
+]=]
+
+--[=[
+	@prop hovered boolean
+	@within BaseComponent
+]=]
+
+--[=[
+	@class Button
+	@extends BaseComponent
+	@implements Clickable
+	@implements BaseComponent.Hoverable
+]=]
+local Button = {}
+
+--[=[
+	@prop label string
+	@within Button
+]=]
+
+--[=[
+	Clicks the button.
+]=]
+function Button:onClick() end
+
+return Button
@@ -0,0 +1,36 @@
+--[=[
+	@class Fabric
+
+	Manages units.
+]=]
+local Fabric = {}
+Fabric.__index = Fabric
+
+--[=[
+	Registers a unit with the fabric.
+
+	@param unitDefinition table
+]=]
+local function registerUnit(unitDefinition) end
+
+--[=[
+	Gets a unit by name.
+]=]
+local getUnitByName = function(name: string) end
+
+--[=[
+	Fires an event on every unit.
+]=]
+Fabric.fire = function(eventName: string) end
+
+--[=[
+	Not exported, but documented with an explicit @within.
+
+	@within Fabric
+]=]
+local function debugPrint() end
+
+Fabric.registerUnit = registerUnit
+Fabric.getUnitByName = getUnitByName
+
+return Fabric
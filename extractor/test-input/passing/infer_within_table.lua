@@ -0,0 +1,14 @@
+--- @class Signal
+--- An event that can be connected to.
+local Signal = {}
+
+--- Creates a new signal.
+local function new() end
+
+--- Connects to the signal.
+local function connect(callback: () -> ()) end
+
+return {
+	new = new,
+	connect = connect,
+}
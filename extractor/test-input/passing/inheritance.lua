@@ -0,0 +1,60 @@
+--[=[
+	@class BaseComponent
+
+	The base of every component.
+]=]
+local BaseComponent = {}
+BaseComponent.__index = BaseComponent
+
+--[=[
+	@prop visible boolean
+	@within BaseComponent
+
+	Whether the component is shown.
+]=]
+
+--[=[
+	Renders the component.
+]=]
+function BaseComponent:render() end
+
+--[=[
+	Removes the component.
+]=]
+function BaseComponent:destroy() end
+
+--[=[
+	@class Button
+	@extends BaseComponent
+
+	A component that can be clicked.
+]=]
+local Button = setmetatable({}, BaseComponent)
+Button.__index = Button
+
+--[=[
+	Renders the button, along with its label.
+]=]
+function Button:render() end
+
+--[=[
+	Clicks the button.
+]=]
+function Button:click() end
+
+--[=[
+	@class IconButton
+	@extends Button
+
+	A button with an icon instead of a label. Like every component, it's shown while
+	[IconButton.visible] is true, and it can be removed with [IconButton:destroy].
+]=]
+local IconButton = setmetatable({}, Button)
+IconButton.__index = IconButton
+
+--[=[
+	@prop icon string
+	@within IconButton
+]=]
+
+return IconButton
@@ -0,0 +1,39 @@
+--- Python-style list class.
+-- Lists are ordered collections of values.
+-- @classmod List
+-- @field n the number of items
+-- @tfield string name the name of the list
+local List = {}
+List.__index = List
+
+--- Create a new list.
+-- @tparam[opt] {any,...} t an optional table of values
+-- @treturn List a new list
+-- @usage
+-- local ls = List.new({10, 20})
+-- print(ls:len())
+function List.new(t)
+	return setmetatable({ items = t or {} }, List)
+end
+
+--- Append a value.
+-- @param value the value to add
+-- @string[opt="end"] position where to add it
+-- @return[type=List] the list, for chaining
+-- @see List.new
+function List:append(value, position)
+	table.insert(self.items, value)
+	return self
+end
+
+--- Length of the list.
+-- @treturn int the number of items
+-- @local
+function List:len()
+	return #self.items
+end
+
+-- a normal comment
+local x = 1
+
+return List
@@ -0,0 +1,21 @@
+--- @module helpers
+
+local M = {}
+
+--- Clamp a number between two bounds.
+-- @number value the number to clamp
+-- @number min the lowest it can be
+-- @number max the highest it can be
+-- @treturn number the clamped number
+function M.clamp(value, min, max)
+	return math.max(min, math.min(max, value))
+end
+
+--- Whether a table has no entries.
+-- @tab t the table to check
+-- @treturn bool whether it's empty
+function M.isEmpty(t)
+	return next(t) == nil
+end
+
+return M
@@ -0,0 +1,100 @@
+--[=[
+	@class Net.Server
+	@extends Emitter
+
+	Serves requests.
+
+	```lua
+	local server = Server.new(8080)
+	```
+]=]
+local Server = {}
+Server.__index = Server
+
+--[=[
+	@class Emitter
+
+	Emits events.
+]=]
+
+--[=[
+	@prop port number
+	@within Net.Server
+	@readonly
+
+	The port the server listens on.
+]=]
+
+--[=[
+	@prop handlers { [string]: Handler }
+	@within Net.Server
+	@private
+]=]
+
+--[=[
+	@type Handler (request: Request, ...any) -> (number, string?)
+	@within Net.Server
+
+	Handles a request.
+]=]
+
+--[=[
+	@interface Request
+	@within Net.Server
+	.path string -- The path requested
+	.headers { string } -- The raw headers
+]=]
+
+--[=[
+	@type Options { timeout: number }
+	@within Net.Server
+]=]
+
+--[=[
+	@type Options { once: boolean }
+	@within Emitter
+
+	A different type with the same name, which is kept apart from the server's.
+]=]
+
+--[=[
+	Starts listening with the given options.
+
+	@param options Options
+]=]
+function Server:listen(options) end
+
+--[=[
+	Creates a server.
+
+	@param port number -- The port to listen on
+	@param host string? -- The host to bind to
+	@return Net.Server
+]=]
+function Server.new(port: number, host: string?)
+	return setmetatable({}, Server)
+end
+
+--[=[
+	Adds a handler for a path.
+
+	@param path string
+	@param handler Handler | (Request) -> ()
+	@deprecated v2 -- Use `route` instead.
+]=]
+function Server:handle(path, handler) end
+
+--[=[
+	Waits for the next request.
+
+	@yields
+	@return Request
+]=]
+function Server:accept(): Request end
+
+--[=[
+	Logs the given values.
+]=]
+function Server.log(...: string) end
+
+return Server
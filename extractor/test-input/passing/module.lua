@@ -0,0 +1,43 @@
+--[=[
+	@module StringUtil
+
+	Helpers for working with strings.
+]=]
+local StringUtil = {}
+
+--[=[
+	Removes whitespace from both ends of a string.
+
+	@param text string
+	@return string
+]=]
+local function trim(text)
+	return text:match("^%s*(.-)%s*$")
+end
+
+--[=[
+	Splits a string on a separator.
+
+	@param text string
+	@param separator string
+	@return { string }
+]=]
+function StringUtil.split(text, separator) end
+
+--[=[
+	Whether a string starts with a prefix.
+]=]
+StringUtil.startsWith = function(text: string, prefix: string): boolean
+	return text:sub(1, #prefix) == prefix
+end
+
+--[=[
+	@prop separator string
+	@within StringUtil
+
+	The separator used when none is given.
+]=]
+
+StringUtil.strip = trim
+
+return StringUtil
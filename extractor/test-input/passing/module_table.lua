@@ -0,0 +1,22 @@
+--[=[
+	@module MathUtil
+]=]
+
+--[=[
+	Clamps a number between two others.
+]=]
+local function clamp(n: number, min: number, max: number): number
+	return math.max(min, math.min(max, n))
+end
+
+--[=[
+	Linearly interpolates between two numbers.
+]=]
+local lerp = function(a: number, b: number, t: number): number
+	return a + (b - a) * t
+end
+
+return {
+	clamp = clamp,
+	lerp = lerp,
+}
@@ -0,0 +1,46 @@
+--[=[
+	@class Net
+
+	Networking utilities.
+]=]
+local Net = {}
+
+--[=[
+	@class Net.Server.Remote
+
+	A remote that the server fires.
+]=]
+Net.Server = {}
+Net.Server.Remote = {}
+Net.Server.Remote.__index = Net.Server.Remote
+
+--[=[
+	Fires the remote for every player.
+]=]
+function Net.Server.Remote:fire() end
+
+--[=[
+	Creates a new remote.
+]=]
+function Net.Server.Remote.new() end
+
+--[=[
+	@function connect
+	@within Net.Server.Remote.__index
+
+	Connects to the remote.
+]=]
+
+--[=[
+	@prop Net.Server.Remote.name string
+
+	The name of the remote.
+]=]
+
+--[=[
+	@class Net.Client
+
+	Networking from the client.
+]=]
+
+return Net
@@ -0,0 +1,30 @@
+--- @class OptionalParams
+
+--[=[
+	Annotated optional parameters.
+	@within OptionalParams
+]=]
+local function annotated(required: string, maybe: number?, either: string | nil, callback: () -> string?) end
+
+--[=[
+	Parameters with default values.
+
+	@within OptionalParams
+	@param retries number = 3 -- How many times to try again
+	@param name? string -- The name to use
+]=]
+local function defaults(retries, name) end
+
+--[=[
+	@function documented
+	@within OptionalParams
+	@param count number = 1 -- The count
+	@param label string? -- An optional label
+	@param ... string -- Extra values
+]=]
+
+--[=[
+	Variadic parameters.
+	@within OptionalParams
+]=]
+local function variadic(first: { number | nil }, ...: string) end
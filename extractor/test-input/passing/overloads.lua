@@ -0,0 +1,55 @@
+--- @class Overloads
+
+--[=[
+	Gets a value by its key.
+
+	@within Overloads
+]=]
+local function get(key: string): number
+	return 0
+end
+
+--[=[
+	@function get
+	@within Overloads
+	@overload
+	@param from number -- The first index
+	@param to number -- The last index
+	@return {number}
+
+	Gets every value within a range of indices.
+]=]
+
+--[=[
+	Converts a value to or from a string.
+
+	@within Overloads
+	@param value -- The value to convert
+]=]
+local convert: ((value: number) -> string) & ((string) -> number) = function(value)
+	return value
+end
+
+--[=[
+	Connects a callback.
+
+	@within Overloads
+]=]
+Overloads.connect = (function(callback, priority)
+	return nil
+end) :: ((callback: () -> ()) -> ()) & ((() -> (), number) -> ())
+
+--[=[
+	@function only
+	@within Overloads
+	@overload
+	@return string
+]=]
+
+--[=[
+	@function only
+	@within Overloads
+	@overload
+	@param count number
+	@return {string}
+]=]
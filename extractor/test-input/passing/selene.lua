@@ -0,0 +1,72 @@
+--[=[
+	@class Sound
+
+	Plays sounds.
+]=]
+local Sound = {}
+
+--[=[
+	@prop volume number
+	@within Sound
+
+	How loud sounds are played.
+]=]
+
+--[=[
+	@prop version string
+	@within Sound
+	@readonly
+]=]
+
+--[=[
+	@prop legacyMode boolean
+	@within Sound
+	@deprecated v2 -- Modes are chosen automatically now.
+]=]
+
+--[=[
+	Plays a sound.
+
+	@param id string
+	@param channel "music" | "effects" | nil
+	@param options { loop: boolean }?
+	@return Instance
+]=]
+function Sound.play(id: string, channel: ("music" | "effects")?, options: { loop: boolean }?): Instance end
+
+--[=[
+	Stops sounds that are playing.
+]=]
+function Sound.stop(instance: Instance, fade: number) end
+
+--[=[
+	@function stop
+	@within Sound
+	@overload
+	@param fade number
+]=]
+
+--[=[
+	Sets how loud sounds are played.
+	@param volume number
+	@deprecated v2
+]=]
+function Sound:setVolume(volume) end
+
+--[=[
+	Plays sounds from the given instance.
+]=]
+function Sound.attach(parent: Instance) end
+
+--[=[
+	Logs some values.
+]=]
+function Sound.log(prefix: string, ...: any) end
+
+--[=[
+	@callback onEnded
+	@within Sound
+	@param id string
+]=]
+
+return Sound
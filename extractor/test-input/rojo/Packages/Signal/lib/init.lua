@@ -0,0 +1,6 @@
+--[=[
+	@class Signal
+
+	Fires events.
+]=]
+return {}
@@ -0,0 +1,6 @@
+--[=[
+	@class Build
+
+	Isn't synced by the project.
+]=]
+return {}
@@ -0,0 +1,6 @@
+--[=[
+	@class Shared
+
+	Code shared between the server and clients.
+]=]
+return {}
@@ -0,0 +1,8 @@
+--[=[
+	@class Store
+
+	Holds state.
+]=]
+local Store = {}
+
+return Store
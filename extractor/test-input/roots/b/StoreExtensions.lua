@@ -0,0 +1,8 @@
+--[=[
+	Resets the store to its initial state. This is in a different input path from the class.
+
+	@within Store
+]=]
+local function reset(store: Store) end
+
+return reset
@@ -1,3 +1,4 @@
+use std::io::Write;
 use std::path::Path;
 use std::process::{Command, Stdio};
 
@@ -85,6 +86,288 @@ fn anomymous_function_assignment() -> anyhow::Result<()> {
     run_moonwave("passing/anonymous_function_assignment.lua", 0)
 }
 
+#[test]
+fn overloads() -> anyhow::Result<()> {
+    run_moonwave("passing/overloads.lua", 0)
+}
+
+#[test]
+fn annotation_mismatch() -> anyhow::Result<()> {
+    run_moonwave("passing/annotation_mismatch.lua", 0)
+}
+
+#[test]
+fn description_links() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/description_links.lua", &["--check-types"], 0)
+}
+
+#[test]
+fn bracket_prose() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/bracket_prose.lua", &["--check-types"], 0)
+}
+
+#[test]
+fn code_blocks() -> anyhow::Result<()> {
+    run_moonwave("passing/code_blocks.lua", 0)
+}
+
+#[test]
+fn examples() -> anyhow::Result<()> {
+    run_moonwave("passing/examples.lua", 0)
+}
+
+#[test]
+fn inheritance() -> anyhow::Result<()> {
+    run_moonwave("passing/inheritance.lua", 0)
+}
+
+#[test]
+fn implements() -> anyhow::Result<()> {
+    run_moonwave("passing/implements.lua", 0)
+}
+
+#[test]
+fn namespaces() -> anyhow::Result<()> {
+    run_moonwave("passing/namespaces.lua", 0)
+}
+
+#[test]
+fn module() -> anyhow::Result<()> {
+    run_moonwave("passing/module.lua", 0)
+}
+
+#[test]
+fn module_table() -> anyhow::Result<()> {
+    run_moonwave("passing/module_table.lua", 0)
+}
+
+#[test]
+fn infer_within() -> anyhow::Result<()> {
+    run_moonwave("passing/infer_within.lua", 0)
+}
+
+#[test]
+fn infer_within_table() -> anyhow::Result<()> {
+    run_moonwave("passing/infer_within_table.lua", 0)
+}
+
+#[test]
+fn events() -> anyhow::Result<()> {
+    run_moonwave("passing/events.lua", 0)
+}
+
+#[test]
+fn constructors_and_callbacks() -> anyhow::Result<()> {
+    run_moonwave("passing/constructors_and_callbacks.lua", 0)
+}
+
+#[test]
+#[cfg(feature = "lua54")]
+fn dialect_lua54() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/dialect_lua54.lua", &["--dialect", "lua54"], 0)
+}
+
+#[test]
+fn emmylua() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/emmylua.lua", &["--compat", "emmylua"], 0)
+}
+
+#[test]
+fn ldoc() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/ldoc.lua", &["--compat", "ldoc"], 0)
+}
+
+#[test]
+fn ldoc_module() -> anyhow::Result<()> {
+    run_moonwave_with_args("passing/ldoc_module.lua", &["--compat", "ldoc"], 0)
+}
+
+#[test]
+fn luals() -> anyhow::Result<()> {
+    run_emit_luals("passing/luals.lua")
+}
+
+#[test]
+fn selene() -> anyhow::Result<()> {
+    run_emit_selene("passing/selene.lua")
+}
+
+#[test]
+fn rojo_project() -> anyhow::Result<()> {
+    run_moonwave_with_args(
+        "rojo",
+        &["--rojo-project", "test-input/rojo/default.project.json"],
+        0,
+    )
+}
+
+#[test]
+fn ignore_files() -> anyhow::Result<()> {
+    run_moonwave_with_args(
+        "ignore",
+        &[
+            "--exclude",
+            "*.spec.lua",
+            "--exclude",
+            "node_modules",
+            "--verbose",
+        ],
+        0,
+    )
+}
+
+/// `.gitignore` files are respected outside of a git checkout too, like in a package that was
+/// downloaded rather than cloned. The fixture is copied somewhere outside this repository first.
+#[test]
+fn gitignore_outside_git() -> anyhow::Result<()> {
+    let root = std::env::temp_dir().join(format!("moonwave-gitignore-{}", std::process::id()));
+    let vendored = root.join("Packages/_Index/lib");
+    let src = root.join("src");
+
+    std::fs::create_dir_all(&vendored)?;
+    std::fs::create_dir_all(&src)?;
+    std::fs::write(root.join(".gitignore"), "Packages/_Index/\n")?;
+    std::fs::copy(
+        "test-input/ignore/Packages/_Index/lib/init.lua",
+        vendored.join("init.lua"),
+    )?;
+    std::fs::copy("test-input/ignore/src/Main.lua", src.join("Main.lua"))?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+        .arg("extract")
+        .arg(&root)
+        .arg("--verbose")
+        .env("NO_COLOR", "1")
+        .output()?;
+
+    std::fs::remove_dir_all(&root)?;
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let stderr = String::from_utf8(output.stderr)?;
+
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stdout.contains("The only class that's documented."));
+    assert!(!stdout.contains("A vendored copy of a dependency."));
+    assert!(
+        stderr.contains("Packages/_Index/lib/init.lua"),
+        "The ignored file isn't listed as skipped:\n{}",
+        stderr
+    );
+
+    Ok(())
+}
+
+#[test]
+fn include_globs() -> anyhow::Result<()> {
+    run_moonwave_with_args("ignore/src", &["--include", "*.spec.lua"], 0)
+}
+
+#[test]
+fn multiple_roots() -> anyhow::Result<()> {
+    run_moonwave_with_args("roots/a", &["test-input/roots/b"], 0)
+}
+
+#[test]
+fn help() -> anyhow::Result<()> {
+    let subcommands = [
+        ("extract", "Extracts doc comments from the given files"),
+        (
+            "emit-luals",
+            "Writes lua-language-server `---@meta` stub files",
+        ),
+        ("emit-selene", "Prints a selene standard library definition"),
+    ];
+
+    for (subcommand, about) in subcommands {
+        let output = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+            .arg(subcommand)
+            .arg("--help")
+            .output()?;
+        let stdout = String::from_utf8(output.stdout)?;
+
+        assert!(output.status.success());
+        assert!(
+            stdout.contains(about),
+            "`{} --help` doesn't describe the subcommand:\n{}",
+            subcommand,
+            stdout
+        );
+    }
+
+    Ok(())
+}
+
+/// Files found through two spellings of the same path are only read once, so this matches the
+/// snapshot of `multiple_roots`
+#[test]
+fn overlapping_roots() -> anyhow::Result<()> {
+    run_moonwave_with_args(
+        "roots/a",
+        &["./test-input/roots/a", "test-input/roots/b"],
+        0,
+    )
+}
+
+#[test]
+fn stdin_filename() -> anyhow::Result<()> {
+    run_moonwave_with_stdin("passing/class_with_index.lua", 0)
+}
+
+#[test]
+fn files_from() -> anyhow::Result<()> {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"));
+    command
+        .arg("extract")
+        .arg("--files-from")
+        .arg("test-input/files_from.txt");
+
+    run_extractor("files_from.txt", command, None, 0)
+}
+
+#[test]
+fn output_file() -> anyhow::Result<()> {
+    let output_path =
+        std::env::temp_dir().join(format!("moonwave-output-{}.json", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+        .arg("extract")
+        .arg("test-input/passing/class_with_function.lua")
+        .arg("--output")
+        .arg(&output_path)
+        .arg("--compact")
+        .output()?;
+
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+
+    let written = std::fs::read_to_string(&output_path)?;
+    std::fs::remove_file(&output_path)?;
+
+    insta::assert_snapshot!("output-compact", written);
+
+    Ok(())
+}
+
+#[test]
+fn check_types() -> anyhow::Result<()> {
+    run_moonwave_with_args(
+        "passing/check_types.lua",
+        &[
+            "--check-types",
+            "--known-type",
+            "Status",
+            "--known-types-file",
+            "test-input/known_types.txt",
+        ],
+        0,
+    )
+}
+
+#[test]
+fn optional_params() -> anyhow::Result<()> {
+    run_moonwave("passing/optional_params.lua", 0)
+}
+
 #[test]
 fn failing_anomymous_function_assignment() -> anyhow::Result<()> {
     run_moonwave("failing/anonymous_function_assignment.lua", 1)
@@ -120,28 +403,92 @@ fn unknown_tags() -> anyhow::Result<()> {
     run_moonwave("failing/unknown_tags.lua", 1)
 }
 
+#[test]
+fn inheritance_errors() -> anyhow::Result<()> {
+    run_moonwave("failing/inheritance.lua", 1)
+}
+
+#[test]
+fn implements_errors() -> anyhow::Result<()> {
+    run_moonwave("failing/implements.lua", 1)
+}
+
+#[test]
+fn module_errors() -> anyhow::Result<()> {
+    run_moonwave("failing/module.lua", 1)
+}
+
+#[test]
+fn event_errors() -> anyhow::Result<()> {
+    run_moonwave("failing/events.lua", 1)
+}
+
+#[test]
+fn constructor_and_callback_errors() -> anyhow::Result<()> {
+    run_moonwave("failing/constructors_and_callbacks.lua", 1)
+}
+
 #[test]
 fn duplicate_names() -> anyhow::Result<()> {
     run_moonwave("failing/duplicate_names.lua", 1)
 }
 
 fn run_moonwave(file_name: &str, expected_status: i32) -> anyhow::Result<()> {
+    run_moonwave_with_args(file_name, &[], expected_status)
+}
+
+fn run_moonwave_with_args(
+    file_name: &str,
+    args: &[&str],
+    expected_status: i32,
+) -> anyhow::Result<()> {
     let path = Path::new("test-input").join(file_name);
+    let mut command = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"));
+    command.arg("extract").arg(path).args(args);
+
+    run_extractor(file_name, command, None, expected_status)
+}
 
-    let child = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+/// Runs the extractor on a file's source given through stdin, rather than on its path
+fn run_moonwave_with_stdin(file_name: &str, expected_status: i32) -> anyhow::Result<()> {
+    let source = std::fs::read_to_string(Path::new("test-input").join(file_name))?;
+    let mut command = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"));
+    command
         .arg("extract")
-        .arg(path)
+        .arg("--stdin-filename")
+        .arg(file_name);
+
+    let name = format!("stdin/{}", file_name);
+    run_extractor(&name, command, Some(source), expected_status)
+}
+
+/// Runs the extractor, snapshotting its stdout and stderr under the given name
+fn run_extractor(
+    name: &str,
+    mut command: Command,
+    stdin: Option<String>,
+    expected_status: i32,
+) -> anyhow::Result<()> {
+    let mut child = command
+        .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
         .env("NO_COLOR", "1")
         .spawn()?;
 
+    // Dropping stdin closes it, so the extractor doesn't wait for more input
+    let mut child_stdin = child.stdin.take().unwrap();
+    if let Some(stdin) = stdin {
+        child_stdin.write_all(stdin.as_bytes())?;
+    }
+    drop(child_stdin);
+
     let output = child.wait_with_output()?;
     let stdout = String::from_utf8(output.stdout)?;
     let stderr = String::from_utf8(output.stderr)?;
 
-    let stdout_name = format!("{}-stdout", file_name);
-    let stderr_name = format!("{}-stderr", file_name);
+    let stdout_name = format!("{}-stdout", name);
+    let stderr_name = format!("{}-stderr", name);
 
     let status_code = output.status.code();
 
@@ -159,3 +506,68 @@ fn run_moonwave(file_name: &str, expected_status: i32) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Runs the `emit-luals` subcommand on a file, snapshotting each stub file it writes
+fn run_emit_luals(file_name: &str) -> anyhow::Result<()> {
+    let path = Path::new("test-input").join(file_name);
+    let out_dir = std::env::temp_dir().join(format!(
+        "moonwave-luals-{}-{}",
+        std::process::id(),
+        file_name.replace('/', "-")
+    ));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+        .arg("emit-luals")
+        .arg(path)
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .env("NO_COLOR", "1")
+        .output()?;
+
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8(output.stderr)?);
+        panic!("Expected emit-luals to succeed");
+    }
+
+    let mut stubs: Vec<_> = std::fs::read_dir(&out_dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<_, _>>()?;
+    stubs.sort();
+
+    for stub in stubs {
+        let stub_name = format!(
+            "{}-luals-{}",
+            file_name,
+            stub.file_name().unwrap().to_string_lossy()
+        );
+
+        insta::assert_snapshot!(stub_name, std::fs::read_to_string(&stub)?);
+    }
+
+    std::fs::remove_dir_all(&out_dir)?;
+
+    Ok(())
+}
+
+/// Runs the `emit-selene` subcommand on a file, snapshotting the standard library it prints
+fn run_emit_selene(file_name: &str) -> anyhow::Result<()> {
+    let path = Path::new("test-input").join(file_name);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_moonwave-extractor"))
+        .arg("emit-selene")
+        .arg(path)
+        .env("NO_COLOR", "1")
+        .output()?;
+
+    if !output.status.success() {
+        eprint!("{}", String::from_utf8(output.stderr)?);
+        panic!("Expected emit-selene to succeed");
+    }
+
+    insta::assert_snapshot!(
+        format!("{}-selene", file_name),
+        String::from_utf8(output.stdout)?
+    );
+
+    Ok(())
+}